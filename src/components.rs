@@ -1,9 +1,11 @@
 use crate::prelude::*;
-use crate::grid::position::*;
 
 pub struct BoardTile;
+pub struct Hint;
 pub struct Hovered;
+pub struct PuzzleList;
 pub struct Selected;
+pub struct SolvabilityIndicator;
 pub struct InbetweenComponent;
 pub struct ValidMoveComponent;
 
@@ -15,14 +17,14 @@ pub struct Token {
 }
 
 impl Token {
-    pub fn from_position(position: &Position) -> Self {
-        let translation = Grid::to_pixel(position);
+    pub fn from_position(position: &Position, layout: &Layout) -> Self {
+        let translation = layout.to_pixel(position);
 
         Self {
             x: translation.x,
             y: translation.y,
-            width: TILE_SIZE_PADDED / 2.0,
-            height: TILE_SIZE_PADDED / 2.0,
+            width: layout.tile_size_padded() / 2.0,
+            height: layout.tile_size_padded() / 2.0,
         }
     }
 }
@@ -1,4 +1,3 @@
-use crate::grid::game_move::*;
 use crate::prelude::*;
 
 pub struct MouseDataEvent {
@@ -9,3 +8,29 @@ pub struct MouseDataEvent {
 pub struct ValidMoveEvent {
     pub data: ValidMove,
 }
+
+pub enum HistoryEvent {
+    Undo,
+    Redo,
+}
+
+pub struct HintEvent;
+
+pub struct NewGameEvent;
+
+pub enum PuzzleSelectEvent {
+    // Opens the puzzle-select screen, or closes it and goes back to the game.
+    Toggle,
+    Previous,
+    Next,
+    Play,
+}
+
+pub enum SaveGameEvent {
+    Save,
+    Load,
+}
+
+pub struct GameOverEvent {
+    pub result: GameResult,
+}
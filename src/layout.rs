@@ -0,0 +1,97 @@
+#[cfg(test)]
+#[path = "./layout_test.rs"]
+mod layout_test;
+
+use crate::prelude::*;
+
+pub const PADDING: f32 = 10.0;
+
+/**
+ * Maps grid positions onto the window and back. The rules in `steckhalma` know nothing about
+ * pixels, so all conversions between the two live here. Tiles are scaled so that the larger side
+ * of the board fills the window, and the board is centered along the other side.
+ */
+pub struct Layout {
+    pub width: usize,
+    pub height: usize,
+    pub tile_size: f32,
+}
+
+impl Layout {
+    pub fn new(grid: &Grid) -> Self {
+        Self {
+            width: grid.width,
+            height: grid.height,
+            tile_size: WINDOW_SIZE / grid.width.max(grid.height) as f32,
+        }
+    }
+
+    pub fn tile_size_padded(&self) -> f32 {
+        self.tile_size - PADDING
+    }
+
+    pub fn to_pixel(&self, position: &Position) -> Vec3 {
+        let x = -(self.width as f32 * self.tile_size / 2.0)
+            + (self.tile_size / 2.0)
+            + (position.col as f32 * self.tile_size);
+        let y = (self.height as f32 * self.tile_size / 2.0)
+            - (self.tile_size / 2.0)
+            - (position.row as f32 * self.tile_size);
+
+        Vec3::new(x, y, 0.0)
+    }
+
+    /**
+     * Takes x & y coordinates that must be already normalized for (0,0) being the top-left corner.
+     */
+    pub fn from_pixel(&self, x: f32, y: f32) -> Position {
+        let (x, y) = self.to_board_pixel(x, y);
+
+        Position {
+            row: (y / self.tile_size).floor() as usize,
+            col: (x / self.tile_size).floor() as usize,
+        }
+    }
+
+    /**
+     * Checked variant of `from_pixel`, which fails for coordinates outside of the grid instead of
+     * clamping them onto the first row or column.
+     */
+    pub fn try_from_pixel(&self, x: f32, y: f32) -> Result<Position, GridError> {
+        let (x, y) = self.to_board_pixel(x, y);
+        let row = (y / self.tile_size).floor();
+        let col = (x / self.tile_size).floor();
+
+        if row < 0.0 || row >= self.height as f32 || col < 0.0 || col >= self.width as f32 {
+            return Err(GridError::OffBoard {
+                row: row as i64,
+                col: col as i64,
+            });
+        }
+
+        Ok(Position {
+            row: row as usize,
+            col: col as usize,
+        })
+    }
+
+    pub fn world_to_grid(translation: Vec3) -> Vec3 {
+        Vec3::new(
+            translation.x + WINDOW_SIZE / 2.0,
+            WINDOW_SIZE / 2.0 - translation.y,
+            0.0,
+        )
+    }
+
+    pub fn ui_to_grid(x: f32, y: f32) -> Vec3 {
+        Vec3::new(x, WINDOW_SIZE - y, 0.0)
+    }
+
+    // Shifts window coordinates so that (0,0) is the top-left corner of the board itself.
+    fn to_board_pixel(&self, x: f32, y: f32) -> (f32, f32) {
+        let offset_x = (WINDOW_SIZE - self.width as f32 * self.tile_size) / 2.0;
+        let offset_y = (WINDOW_SIZE - self.height as f32 * self.tile_size) / 2.0;
+
+        (x - offset_x, y - offset_y)
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn test_ui_to_grid() {
+    assert_eq!(Layout::ui_to_grid(0.0, 500.0), Vec3::new(0.0, 0.0, 0.0));
+    assert_eq!(Layout::ui_to_grid(500.0, 500.0), Vec3::new(500.0, 0.0, 0.0));
+    assert_eq!(Layout::ui_to_grid(250.0, 250.0), Vec3::new(250.0, 250.0, 0.0));
+}
+
+#[test]
+fn test_world_to_grid() {
+    assert_eq!(Layout::world_to_grid(Vec3::new(0.0, 0.0, 0.0)), Vec3::new(250.0, 250.0, 0.0));
+    assert_eq!(Layout::world_to_grid(Vec3::new(250.0, 250.0, 0.0)), Vec3::new(500.0, 0.0, 0.0));
+    assert_eq!(Layout::world_to_grid(Vec3::new(500.0, 500.0, 0.0)), Vec3::new(750.0, -250.0, 0.0));
+}
+
+#[test]
+fn test_from_pixel() {
+    let layout = Layout::new(&Grid::new());
+
+    assert_eq!(layout.from_pixel(0.0, 0.0), Position {row: 0, col: 0});
+    assert_eq!(layout.from_pixel(250.0, 0.0), Position {row: 0, col: 3});
+    assert_eq!(layout.from_pixel(499.0, 0.0), Position {row: 0, col: 6});
+    assert_eq!(layout.from_pixel(0.0, 250.0), Position {row: 3, col: 0});
+    assert_eq!(layout.from_pixel(0.0, 499.0), Position {row: 6, col: 0});
+    assert_eq!(layout.from_pixel(250.0, 250.0), Position {row: 3, col: 3});
+}
+
+#[test]
+fn test_to_pixel() {
+    let layout = Layout::new(&Grid::new());
+
+    assert_eq!(layout.to_pixel(&Position {row: 0, col: 0}), Vec3::new(-214.2857, 214.2857, 0.0));
+    assert_eq!(layout.to_pixel(&Position {row: 0, col: 6}), Vec3::new(214.28574, 214.2857, 0.0));
+    assert_eq!(layout.to_pixel(&Position {row: 3, col: 3}), Vec3::new(0.000015258789, -0.000015258789, 0.0));
+    assert_eq!(layout.to_pixel(&Position {row: 6, col: 0}), Vec3::new(-214.2857, -214.28574, 0.0));
+    assert_eq!(layout.to_pixel(&Position {row: 6, col: 6}), Vec3::new(214.28574, -214.28574, 0.0));
+}
+
+#[test]
+fn test_try_from_pixel() {
+    let layout = Layout::new(&Grid::new());
+
+    assert_eq!(layout.try_from_pixel(250.0, 250.0), Ok(Position {row: 3, col: 3}));
+    assert_eq!(layout.try_from_pixel(-1.0, 250.0), Err(GridError::OffBoard {row: 3, col: -1}));
+    assert_eq!(layout.try_from_pixel(250.0, 500.0), Err(GridError::OffBoard {row: 7, col: 3}));
+}
+
+#[test]
+fn test_layout_scales_to_board() {
+    let layout = Layout::new(&Grid::cross(5).unwrap());
+
+    assert_eq!(layout.tile_size, 100.0);
+    assert_eq!(layout.to_pixel(&Position {row: 0, col: 0}), Vec3::new(-200.0, 200.0, 0.0));
+    assert_eq!(layout.from_pixel(499.0, 499.0), Position {row: 4, col: 4});
+
+    let wide = Layout::new(&Grid::from_mask(5, 4, &[true; 20]));
+    assert_eq!(wide.try_from_pixel(250.0, 40.0), Err(GridError::OffBoard {row: -1, col: 2}));
+    assert_eq!(wide.try_from_pixel(250.0, 60.0), Ok(Position {row: 0, col: 2}));
+}
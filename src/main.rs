@@ -1,6 +1,9 @@
 mod components;
 mod events;
-mod grid;
+mod layout;
+mod options;
+mod progress;
+mod puzzle_pack;
 mod systems;
 
 mod prelude {
@@ -8,21 +11,39 @@ mod prelude {
 
     pub use bevy::prelude::*;
 
+    pub use steckhalma::prelude::*;
+
     pub use crate::components::*;
     pub use crate::events::*;
-    pub use crate::grid::*;
+    pub use crate::layout::*;
+    pub use crate::options::*;
+    pub use crate::progress::*;
+    pub use crate::puzzle_pack::*;
 
+    pub use crate::systems::feasibility::*;
+    pub use crate::systems::game_over::*;
+    pub use crate::systems::hint::*;
+    pub use crate::systems::history::*;
+    pub use crate::systems::hole_setup::*;
+    pub use crate::systems::hud::*;
+    pub use crate::systems::keyboard_input::*;
     pub use crate::systems::mouse_input::*;
     pub use crate::systems::player_move::*;
     pub use crate::systems::process_mouse_input::*;
+    pub use crate::systems::puzzle_select::*;
     pub use crate::systems::render::*;
+    pub use crate::systems::save_game::*;
     pub use crate::systems::setup::*;
+    pub use crate::systems::solvability::*;
     pub use crate::systems::*;
 }
 
 use prelude::*;
 
 fn main() {
+    let options = Options::from_args();
+    let grid = options.initial_grid();
+
     App::build()
         .insert_resource(WindowDescriptor {
             title: "Steckhalma".to_string(),
@@ -32,10 +53,25 @@ fn main() {
             ..Default::default()
         })
         .insert_resource(ClearColor(Color::rgb(0.0, 0.0, 0.0)))
-        .insert_resource(Grid::new())
+        .insert_resource(Layout::new(&grid))
+        .insert_resource(HoleSetup::from_options(&options))
+        .insert_resource(Feasibility::default())
+        .insert_resource(Solvability::default())
+        .insert_resource(GameReview::default())
+        .insert_resource(PuzzleSelect::default())
+        .insert_resource(CurrentPuzzle(options.puzzle.clone()))
+        .insert_resource(grid)
+        .insert_resource(options)
         .add_event::<MouseDataEvent>()
         .add_event::<ValidMoveEvent>()
+        .add_event::<HistoryEvent>()
+        .add_event::<HintEvent>()
+        .add_event::<NewGameEvent>()
+        .add_event::<PuzzleSelectEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<SaveGameEvent>()
         .add_startup_system(setup.system())
+        .add_startup_system(setup_puzzles.system())
         .add_startup_stage("game_setup", SystemStage::single(setup_board.system()))
         .add_system(mouse_input.system().label("mouse_input"))
         .add_system(
@@ -50,6 +86,111 @@ fn main() {
                 .label("handle_move")
                 .after("process_mouse_input"),
         )
+        .add_system(keyboard_input.system().label("keyboard_input"))
+        .add_system(
+            puzzle_select
+                .system()
+                .label("handle_puzzle_select")
+                .after("keyboard_input"),
+        )
+        .add_system(
+            hole_setup
+                .system()
+                .label("handle_hole_setup")
+                .after("keyboard_input")
+                .after("process_mouse_input"),
+        )
+        .add_system(
+            history
+                .system()
+                .label("handle_history")
+                .after("keyboard_input")
+                .after("handle_move"),
+        )
+        .add_system(
+            request_hint
+                .system()
+                .label("request_hint")
+                .after("keyboard_input")
+                .after("handle_history"),
+        )
+        .add_system(
+            show_hint
+                .system()
+                .label("handle_hint")
+                .after("request_hint")
+                .after("handle_move")
+                .after("handle_history"),
+        )
+        .add_system(
+            save_game
+                .system()
+                .label("handle_save_game")
+                .after("keyboard_input")
+                .after("handle_history"),
+        )
+        .add_system(
+            check_game_over
+                .system()
+                .label("check_game_over")
+                .after("handle_move")
+                .after("handle_history")
+                .after("handle_hole_setup")
+                .after("handle_puzzle_select")
+                .after("handle_save_game"),
+        )
+        .add_system(
+            announce_game_over
+                .system()
+                .label("announce_game_over")
+                .after("check_game_over"),
+        )
+        .add_system(
+            update_review
+                .system()
+                .label("update_review")
+                .after("announce_game_over"),
+        )
+        .add_system(record_puzzle_result.system().after("check_game_over"))
+        .add_system(
+            check_feasibility
+                .system()
+                .label("check_feasibility")
+                .after("handle_move")
+                .after("handle_history")
+                .after("handle_hole_setup")
+                .after("handle_puzzle_select")
+                .after("handle_save_game"),
+        )
+        .add_system(
+            check_solvability
+                .system()
+                .label("check_solvability")
+                .after("handle_move")
+                .after("handle_history")
+                .after("handle_hole_setup")
+                .after("handle_puzzle_select")
+                .after("handle_save_game"),
+        )
+        .add_system(
+            update_solvability
+                .system()
+                .label("update_solvability")
+                .after("check_solvability"),
+        )
+        .add_system(
+            update_hud
+                .system()
+                .after("check_feasibility")
+                .after("update_solvability")
+                .after("update_review")
+                .after("handle_move")
+                .after("handle_history")
+                .after("handle_hint")
+                .after("handle_hole_setup")
+                .after("handle_puzzle_select")
+                .after("handle_save_game"),
+        )
         .add_system(
             render
                 .system()
@@ -58,5 +199,7 @@ fn main() {
         )
         .add_system(bevy::input::system::exit_on_esc_system.system())
         .add_plugins(DefaultPlugins)
+        .add_asset::<PuzzlePack>()
+        .init_asset_loader::<PuzzlePackLoader>()
         .run()
 }
@@ -0,0 +1,173 @@
+use std::path::PathBuf;
+
+use crate::prelude::*;
+
+pub const DEFAULT_SAVE_PATH: &str = "steckhalma.save";
+
+/**
+ * Settings picked on the command line when starting the game, e.g. `--board french`,
+ * `--load game.save`, `--moves "d2-d4 f3-d3"`, `--vacancy c1 --finish f4`, `--setup` or
+ * `--puzzle cross`.
+ */
+pub struct Options {
+    pub variant: BoardVariant,
+    // Saved game to continue instead of starting a new one.
+    pub load: Option<PathBuf>,
+    // Moves in algebraic or numeric notation to play before handing over to the player.
+    pub moves: Option<String>,
+    // File written and read by the save and load shortcuts.
+    pub save_path: PathBuf,
+    // Hole left empty at the start of a new game instead of the board's usual one.
+    pub vacancy: Option<Position>,
+    // Hole the last token has to end up in instead of the board's usual one. Ignored for puzzles,
+    // as their goal pattern says where the tokens end up.
+    pub finish: Option<Position>,
+    // Whether to start by clicking the vacancy and the finish hole on the board.
+    pub setup: bool,
+    // Classic problem to play instead of clearing the whole board.
+    pub puzzle: Option<Puzzle>,
+}
+
+impl Options {
+    pub fn from_args() -> Self {
+        let mut options = Self {
+            variant: BoardVariant::English,
+            load: None,
+            moves: None,
+            save_path: PathBuf::from(DEFAULT_SAVE_PATH),
+            vacancy: None,
+            finish: None,
+            setup: false,
+            puzzle: None,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--board" => match args.next().as_deref().and_then(BoardVariant::from_name) {
+                    Some(variant) => options.variant = variant,
+                    None => eprintln!("Unknown board, expected one of: {}", Options::board_names()),
+                },
+                "--load" => match args.next() {
+                    Some(path) => {
+                        options.save_path = PathBuf::from(&path);
+                        options.load = Some(PathBuf::from(path));
+                    }
+                    None => eprintln!("Missing file name after --load"),
+                },
+                "--moves" => match args.next() {
+                    Some(moves) => options.moves = Some(moves),
+                    None => eprintln!("Missing moves after --moves"),
+                },
+                "--vacancy" => options.vacancy = Options::parse_hole(&arg, args.next()),
+                "--finish" => options.finish = Options::parse_hole(&arg, args.next()),
+                "--setup" => options.setup = true,
+                "--puzzle" => match args.next().as_deref().and_then(Puzzle::from_name) {
+                    Some(puzzle) => options.puzzle = Some(puzzle),
+                    None => eprintln!(
+                        "Unknown puzzle, expected one of: {}",
+                        Options::puzzle_names()
+                    ),
+                },
+                _ => eprintln!("Ignoring unknown argument {}", arg),
+            }
+        }
+
+        options
+    }
+
+    /**
+     * The grid to start with: the saved game if one was given and can be read, otherwise the
+     * chosen puzzle or a new game on the chosen board from the chosen vacancy. The finish hole only
+     * applies to games without a goal pattern. Any moves given are played on top of it.
+     */
+    pub fn initial_grid(&self) -> Grid {
+        let loaded = self.load.as_ref().and_then(|path| match Grid::load(path) {
+            Ok(grid) => Some(grid),
+            Err(error) => {
+                eprintln!("Could not load {}: {}", path.display(), error);
+                None
+            }
+        });
+        let mut grid = loaded
+            .or_else(|| self.puzzle.as_ref().map(|puzzle| puzzle.grid()))
+            .unwrap_or_else(|| self.new_grid());
+
+        // A puzzle is won by its goal pattern, so a finish hole would never be looked at.
+        if self.finish.is_some() && grid.pattern.is_some() {
+            eprintln!("Ignoring --finish, the puzzle's goal pattern says where the tokens end up");
+        } else if let Some(finish) = &self.finish {
+            match grid.with_finish(finish) {
+                Ok(finished) => grid = finished,
+                Err(error) => eprintln!(
+                    "Could not finish in {}: {}",
+                    finish.to_notation(Notation::Algebraic),
+                    error
+                ),
+            }
+        }
+
+        if let Some(moves) = &self.moves {
+            if let Err(error) = Options::play_moves(&mut grid, moves) {
+                eprintln!("Could not play {:?}: {}", moves, error);
+            }
+        }
+
+        grid
+    }
+
+    fn new_grid(&self) -> Grid {
+        let grid = Grid::from_variant(self.variant);
+        let vacancy = match &self.vacancy {
+            Some(vacancy) => vacancy,
+            None => return grid,
+        };
+
+        grid.with_vacancy(vacancy).unwrap_or_else(|error| {
+            eprintln!(
+                "Could not start from {}: {}",
+                vacancy.to_notation(Notation::Algebraic),
+                error
+            );
+            grid
+        })
+    }
+
+    fn parse_hole(flag: &str, arg: Option<String>) -> Option<Position> {
+        match arg.as_deref().map(Position::from_notation) {
+            Some(Ok(position)) => Some(position),
+            Some(Err(error)) => {
+                eprintln!("Ignoring {}: {}", flag, error);
+                None
+            }
+            None => {
+                eprintln!("Missing hole after {}", flag);
+                None
+            }
+        }
+    }
+
+    fn play_moves(grid: &mut Grid, moves: &str) -> Result<(), Box<dyn std::error::Error>> {
+        for valid_move in parse_moves(moves)? {
+            grid.apply_move(&valid_move)?;
+        }
+
+        Ok(())
+    }
+
+    fn board_names() -> String {
+        BoardVariant::ALL
+            .iter()
+            .map(|variant| variant.name())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+
+    fn puzzle_names() -> String {
+        Puzzle::classics()
+            .into_iter()
+            .map(|puzzle| puzzle.name.to_lowercase())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::prelude::*;
+
+pub const DEFAULT_PROGRESS_PATH: &str = "steckhalma.progress";
+
+/**
+ * The fewest moves each puzzle was solved in, by name. It is kept in a plain text file with one
+ * line per solved puzzle, e.g. `Cross: 5`.
+ */
+pub struct PuzzleProgress {
+    path: PathBuf,
+    best: HashMap<String, usize>,
+}
+
+impl PuzzleProgress {
+    /**
+     * Reads the progress file, starting from scratch if there is none yet.
+     */
+    pub fn load(path: &Path) -> Self {
+        let mut best = HashMap::new();
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                for line in text.lines() {
+                    let record = line
+                        .rsplit_once(':')
+                        .and_then(|(name, moves)| Some((name.trim(), moves.trim().parse().ok()?)));
+                    match record {
+                        Some((name, moves)) => {
+                            best.insert(name.to_string(), moves);
+                        }
+                        None => warn!("Ignoring {:?} in {}", line, path.display()),
+                    }
+                }
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => warn!("Could not read {}: {}", path.display(), error),
+        }
+
+        Self {
+            path: path.to_path_buf(),
+            best,
+        }
+    }
+
+    pub fn best(&self, name: &str) -> Option<usize> {
+        self.best.get(name).copied()
+    }
+
+    /**
+     * Remembers a solution of the puzzle and returns whether it beats the previous best.
+     */
+    pub fn record(&mut self, name: &str, moves: usize) -> bool {
+        if self.best(name).is_some_and(|best| best <= moves) {
+            return false;
+        }
+
+        self.best.insert(name.to_string(), moves);
+        if let Err(error) = fs::write(&self.path, self.to_progress_string()) {
+            warn!("Could not save {}: {}", self.path.display(), error);
+        }
+
+        true
+    }
+
+    fn to_progress_string(&self) -> String {
+        let mut names: Vec<&String> = self.best.keys().collect();
+        names.sort();
+
+        names
+            .iter()
+            .map(|name| format!("{}: {}\n", name, self.best[*name]))
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+#[path = "./puzzle_pack_test.rs"]
+mod puzzle_pack_test;
+
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy::reflect::TypeUuid;
+use bevy::utils::BoxedFuture;
+
+use crate::prelude::*;
+
+/**
+ * Folder below `assets` with the puzzle packs to offer next to the classics. Every `.puzzles` file
+ * in it is loaded, so new puzzles only need a new file.
+ */
+pub const PUZZLE_FOLDER: &str = "puzzles";
+
+/**
+ * A puzzle pack file, see `puzzle_pack.rs` of the steckhalma crate for the format.
+ */
+#[derive(Debug, TypeUuid)]
+#[uuid = "8c1b5d2e-3f4a-4b6c-9d7e-2a1f0e5c4b3d"]
+pub struct PuzzlePack {
+    // Path of the file below `assets`, which orders the packs.
+    pub path: String,
+    pub puzzles: Vec<Puzzle>,
+}
+
+#[derive(Default)]
+pub struct PuzzlePackLoader;
+
+impl AssetLoader for PuzzlePackLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let text = std::str::from_utf8(bytes)?;
+            let pack = PuzzlePack {
+                path: load_context.path().display().to_string(),
+                puzzles: Puzzle::parse_pack(text)?,
+            };
+            load_context.set_default_asset(LoadedAsset::new(pack));
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["puzzles"]
+    }
+}
+
+/**
+ * Handles of the packs found in the puzzle folder. Packs that fail to load are only logged.
+ */
+pub struct PuzzlePacks {
+    pub handles: Vec<HandleUntyped>,
+}
+
+impl PuzzlePacks {
+    /**
+     * The classics followed by the puzzles of every pack loaded so far.
+     */
+    pub fn puzzles(&self, packs: &Assets<PuzzlePack>) -> Vec<Puzzle> {
+        let mut loaded: Vec<&PuzzlePack> = self
+            .handles
+            .iter()
+            .filter_map(|handle| packs.get(handle))
+            .collect();
+        loaded.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Puzzle::classics()
+            .into_iter()
+            .chain(loaded.into_iter().flat_map(|pack| pack.puzzles.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn test_asset_packs_parse() {
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(PUZZLE_FOLDER);
+    let mut names: HashSet<String> = Puzzle::classics().into_iter().map(|puzzle| puzzle.name).collect();
+    let mut packs = 0;
+
+    for entry in fs::read_dir(&folder).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("puzzles") {
+            continue;
+        }
+
+        let text = fs::read_to_string(&path).unwrap();
+        let puzzles = Puzzle::parse_pack(&text).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+        assert!(!puzzles.is_empty(), "{} has no puzzles", path.display());
+
+        // Progress is kept by name, so no two puzzles may share one.
+        for puzzle in puzzles {
+            assert!(names.insert(puzzle.name.clone()), "{} is defined twice", puzzle.name);
+        }
+        packs += 1;
+    }
+
+    assert!(packs > 0);
+}
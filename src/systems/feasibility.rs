@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/**
+ * What the quick checks prove about the current position, shown in the HUD.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Feasibility {
+    // Position class of the tokens, and whether the goal lies in another class.
+    pub class: Option<(PositionClass, bool)>,
+    // Sum of the board's pagoda over the tokens and the sum the goal needs, when it is too low.
+    pub pagoda: Option<(i32, i32)>,
+}
+
+/**
+ * Tells the player once the goal can no longer be reached, with the proof: first the cheap
+ * position class check, then the board's pagoda. The message is repeated only after undoing back
+ * to a position where the goal was still possible.
+ */
+pub fn check_feasibility(
+    grid: Res<Grid>,
+    mut feasibility: ResMut<Feasibility>,
+    mut ruled_out: Local<bool>,
+) {
+    if !grid.is_changed() {
+        return;
+    }
+
+    let goal = Goal::for_grid(&grid);
+    let pagoda = Pagoda::for_grid(&grid).filter(|pagoda| goal.is_ruled_out_by(pagoda, &grid));
+
+    // Only touch the resource when the verdict changes, so the HUD is not redrawn for nothing.
+    let class = Some((grid.position_class(), goal.is_ruled_out_by_class(&grid)));
+    if feasibility.class != class {
+        feasibility.class = class;
+    }
+    let pagoda_sums = pagoda
+        .as_ref()
+        .map(|pagoda| (pagoda.value(&grid), goal.pagoda_value(pagoda).unwrap()));
+    if feasibility.pagoda != pagoda_sums {
+        feasibility.pagoda = pagoda_sums;
+    }
+
+    let proof = if goal.is_ruled_out_by_class(&grid) {
+        Some(format!(
+            "The tokens are in position class {}, {} is in class {}, and no jump changes the \
+            class.",
+            grid.position_class(),
+            goal,
+            goal.position_class(&grid).unwrap()
+        ))
+    } else {
+        pagoda.zip(pagoda_sums).map(|(pagoda, (value, needed))| {
+            format!(
+                "The pagoda below sums to {} over the tokens, {} needs {}, and no jump ever \
+                increases the sum:\n{}",
+                value, goal, needed, pagoda
+            )
+        })
+    };
+
+    match proof {
+        Some(proof) if !*ruled_out => {
+            *ruled_out = true;
+            info!("This position can no longer reach {}. {}", goal, proof);
+        }
+        Some(_) => {}
+        None => *ruled_out = false,
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use futures_lite::future;
+
+use crate::prelude::*;
+
+pub fn check_game_over(grid: Res<Grid>, mut game_over_writer: EventWriter<GameOverEvent>) {
+    if !grid.is_changed() {
+        return;
+    }
+
+    if let Some(result) = grid.game_result() {
+        game_over_writer.send(GameOverEvent { result });
+    }
+}
+
+/**
+ * A review of a lost game running in the background, for the position with the given Zobrist hash.
+ * It gives `None` once the solver runs out of positions to search.
+ */
+pub struct ReviewTask {
+    pub position: u64,
+    pub task: Task<Result<Option<Review>, GridError>>,
+    // Stops the review once another game is over.
+    pub cancel: Arc<AtomicBool>,
+}
+
+/**
+ * The last finished review, with the Zobrist hash of the position it was made for.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GameReview {
+    pub verdict: Option<(u64, Review)>,
+}
+
+/**
+ * Logs the result of the game, and reviews a lost game in the background, cancelling the review of
+ * an earlier game over.
+ */
+pub fn announce_game_over(
+    mut commands: Commands,
+    grid: Res<Grid>,
+    thread_pool: Res<AsyncComputeTaskPool>,
+    mut game_over_reader: EventReader<GameOverEvent>,
+    tasks: Query<(Entity, &ReviewTask)>,
+) {
+    for event in game_over_reader.iter() {
+        info!(
+            "Moves played ({}): {}",
+            grid.moves().len(),
+            format_moves(&grid.history, Notation::Algebraic)
+        );
+        info!("Hints used: {}", grid.hints.len());
+
+        match event.result {
+            GameResult::Perfect => info!("Game over: {} is reached!", Goal::for_grid(&grid)),
+            GameResult::SinglePeg(position) => info!(
+                "Game over: a single token is left at row {}, col {}.",
+                position.row, position.col
+            ),
+            GameResult::Stranded(count) => {
+                info!("Game over: {} tokens are stranded.", count);
+                for (entity, review) in tasks.iter() {
+                    review.cancel.store(true, Ordering::Relaxed);
+                    commands.entity(entity).despawn();
+                }
+
+                let position = grid.clone();
+                let goal = Goal::for_grid(&grid).relaxed();
+                let cancel = Arc::new(AtomicBool::new(false));
+                let flag = cancel.clone();
+                let task = thread_pool.spawn(async move {
+                    let mut solver = Solver::new(goal)
+                        .with_cancel(flag)
+                        .with_node_limit(SOLVER_NODE_LIMIT);
+                    let review = solver.review(&position);
+
+                    review.map(|review| (!solver.is_out_of_budget()).then_some(review))
+                });
+
+                commands.spawn().insert(ReviewTask {
+                    position: grid.zobrist_hash(),
+                    task,
+                    cancel,
+                });
+            }
+        }
+    }
+}
+
+/**
+ * Points out the move that made a single token impossible, and what could have been played instead,
+ * once the review is done. The verdict stays in the HUD while the lost position is on the board.
+ */
+pub fn update_review(
+    mut commands: Commands,
+    mut game_review: ResMut<GameReview>,
+    mut tasks: Query<(Entity, &mut ReviewTask)>,
+) {
+    for (entity, mut review) in tasks.iter_mut() {
+        if review.cancel.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let result = match future::block_on(future::poll_once(&mut review.task)) {
+            Some(result) => result,
+            None => continue,
+        };
+        commands.entity(entity).despawn();
+
+        if let Ok(Some(verdict)) = &result {
+            game_review.verdict = Some((review.position, verdict.clone()));
+        }
+        announce_review(result);
+    }
+}
+
+fn announce_review(result: Result<Option<Review>, GridError>) {
+    match result {
+        Ok(None) => info!(
+            "Review: gave up after searching {} positions.",
+            SOLVER_NODE_LIMIT
+        ),
+        Ok(Some(Review::Reachable)) => {}
+        Ok(Some(Review::UnreachableFromStart)) => {
+            info!("Review: a single token could not be reached from the starting position.")
+        }
+        Ok(Some(Review::Mistake {
+            index,
+            played,
+            alternatives,
+        })) => {
+            info!(
+                "Review: move {}, {}, made a single token impossible.",
+                index + 1,
+                played.to_notation(Notation::Algebraic)
+            );
+
+            if !alternatives.is_empty() {
+                let alternatives: Vec<String> = alternatives
+                    .iter()
+                    .map(|valid_move| valid_move.to_notation(Notation::Algebraic))
+                    .collect();
+                info!(
+                    "Review: winning alternatives were {}",
+                    alternatives.join(", ")
+                );
+            }
+        }
+        Err(error) => warn!("Review: {}", error),
+    }
+}
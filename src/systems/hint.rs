@@ -0,0 +1,151 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use futures_lite::future;
+
+use crate::prelude::*;
+
+/**
+ * A solver looking for a hint in the background, for the position with the given Zobrist hash.
+ * Along with the hint, it tells whether the solver gave up before finding one.
+ */
+pub struct HintTask {
+    pub position: u64,
+    pub task: Task<(Result<Option<ValidMove>, GridError>, bool)>,
+    // Stops the solver once a new hint is asked for.
+    pub cancel: Arc<AtomicBool>,
+}
+
+/**
+ * Starts looking for the first move of a solution in the background, cancelling a search that is
+ * still running. Aims for the goal of the game and falls back to a single token anywhere if the
+ * finish hole is out of reach.
+ */
+pub fn request_hint(
+    mut commands: Commands,
+    grid: Res<Grid>,
+    thread_pool: Res<AsyncComputeTaskPool>,
+    mut hint_reader: EventReader<HintEvent>,
+    tasks: Query<(Entity, &HintTask)>,
+) {
+    if hint_reader.iter().next().is_none() {
+        return;
+    }
+
+    for (entity, search) in tasks.iter() {
+        search.cancel.store(true, Ordering::Relaxed);
+        commands.entity(entity).despawn();
+    }
+
+    let position = grid.clone();
+    let goal = Goal::for_grid(&grid);
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+    let task = thread_pool.spawn(async move {
+        let mut solver = Solver::new(goal.clone())
+            .with_cancel(flag.clone())
+            .with_node_limit(SOLVER_NODE_LIMIT);
+        let mut hint = solver.hint(&position);
+        if hint == Ok(None) && goal.relaxed() != goal {
+            solver = Solver::new(goal.relaxed())
+                .with_cancel(flag)
+                .with_node_limit(SOLVER_NODE_LIMIT);
+            hint = solver.hint(&position);
+        }
+
+        (hint, solver.is_out_of_budget())
+    });
+
+    commands.spawn().insert(HintTask {
+        position: grid.zobrist_hash(),
+        task,
+        cancel,
+    });
+}
+
+/**
+ * Shows the hint once it is found, with the usual selection highlights in the hint colour. A hint
+ * for a position the player has left in the meantime is dropped.
+ */
+pub fn show_hint(
+    mut commands: Commands,
+    mut grid: ResMut<Grid>,
+    layout: Res<Layout>,
+    mut tasks: Query<(Entity, &mut HintTask)>,
+    board_tiles: Query<(Entity, &Transform), With<BoardTile>>,
+) {
+    for (entity, mut search) in tasks.iter_mut() {
+        if search.cancel.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let (hint, gave_up) = match future::block_on(future::poll_once(&mut search.task)) {
+            Some(result) => result,
+            None => continue,
+        };
+        commands.entity(entity).despawn();
+
+        if search.position != grid.zobrist_hash() {
+            continue;
+        }
+
+        let hint = match hint {
+            Ok(Some(hint)) => hint,
+            Ok(None) if gave_up => {
+                info!(
+                    "No hint: gave up after searching {} positions.",
+                    SOLVER_NODE_LIMIT
+                );
+                continue;
+            }
+            Ok(None) => {
+                info!(
+                    "No hint: no move leads to {} anymore.",
+                    Goal::for_grid(&grid).relaxed()
+                );
+                continue;
+            }
+            Err(error) => {
+                warn!("No hint: {}", error);
+                continue;
+            }
+        };
+
+        let moves_played = grid.history.len();
+        grid.hints.push(moves_played);
+        info!(
+            "Hint {}: {}",
+            grid.hints.len(),
+            hint.to_notation(Notation::Algebraic)
+        );
+
+        for (entity, transform) in board_tiles.iter() {
+            let normalized = Layout::world_to_grid(transform.translation);
+            let position = layout.from_pixel(normalized.x, normalized.y);
+
+            let mut tile = commands.entity(entity);
+            tile.remove::<Selected>();
+            tile.remove::<ValidMoveComponent>();
+            tile.remove::<InbetweenComponent>();
+            tile.remove::<Hint>();
+
+            if position == hint.origin {
+                tile.insert(Selected {}).insert(Hint {});
+            } else if position == hint.middle {
+                tile.insert(InbetweenComponent {}).insert(Hint {});
+            } else if position == hint.target {
+                tile.insert(ValidMoveComponent {}).insert(Hint {});
+            }
+        }
+    }
+}
+
+/**
+ * Drops the hint highlight once the position it was given for is gone.
+ */
+pub fn clear_hint(commands: &mut Commands, hints: &Query<Entity, With<Hint>>) {
+    for entity in hints.iter() {
+        commands.entity(entity).remove::<Hint>();
+    }
+}
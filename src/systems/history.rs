@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+pub fn history(
+    mut commands: Commands,
+    mut grid: ResMut<Grid>,
+    layout: Res<Layout>,
+    materials: Res<Materials>,
+    mut history_reader: EventReader<HistoryEvent>,
+    tokens: Query<(Entity, &Transform), With<Token>>,
+    hints: Query<Entity, With<Hint>>,
+) {
+    let history_reader = history_reader.iter().next();
+    if history_reader.is_none() {
+        return;
+    }
+
+    // Going through `ResMut` marks the grid as changed, which would report the game over again
+    // when there is nothing to undo or redo.
+    let event = history_reader.unwrap();
+    let nothing_to_do = match event {
+        HistoryEvent::Undo => grid.history.is_empty(),
+        HistoryEvent::Redo => grid.undone.is_empty(),
+    };
+    if nothing_to_do {
+        return;
+    }
+
+    clear_hint(&mut commands, &hints);
+
+    match event {
+        HistoryEvent::Undo => {
+            if let Some(move_undone) = grid.undo() {
+                move_token(
+                    &mut commands,
+                    &layout,
+                    &tokens,
+                    &move_undone.target,
+                    &move_undone.origin,
+                );
+                spawn_token(&mut commands, &layout, &materials, &move_undone.middle);
+            }
+        }
+        HistoryEvent::Redo => {
+            if let Some(move_redone) = grid.redo() {
+                move_token(
+                    &mut commands,
+                    &layout,
+                    &tokens,
+                    &move_redone.origin,
+                    &move_redone.target,
+                );
+                despawn_token(&mut commands, &layout, &tokens, &move_redone.middle);
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/**
+ * Before a game, the player may click the hole to leave empty at the start and then the hole the
+ * last token has to end up in. Clicks only go to the board once both are chosen.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HoleSetup {
+    // Waiting for a click on the starting vacancy.
+    Vacancy,
+    // Waiting for a click on the finish hole.
+    Finish,
+    // Both holes are chosen and the game is on.
+    Done,
+}
+
+impl HoleSetup {
+    pub fn from_options(options: &Options) -> Self {
+        match options.setup {
+            true => HoleSetup::Vacancy,
+            false => HoleSetup::Done,
+        }
+    }
+
+    /**
+     * What the player is asked to do, or `None` while playing.
+     */
+    pub fn prompt(&self) -> Option<&'static str> {
+        match self {
+            HoleSetup::Vacancy => Some("Click the hole to leave empty"),
+            HoleSetup::Finish => Some("Click the hole to finish in"),
+            HoleSetup::Done => None,
+        }
+    }
+}
+
+pub fn hole_setup(
+    mut commands: Commands,
+    mut grid: ResMut<Grid>,
+    mut layout: ResMut<Layout>,
+    materials: Res<Materials>,
+    mut setup: ResMut<HoleSetup>,
+    puzzle_select: Res<PuzzleSelect>,
+    mut new_game_reader: EventReader<NewGameEvent>,
+    mut mouse_data_reader: EventReader<MouseDataEvent>,
+    mut was_clicked: Local<bool>,
+    board: Query<Entity, Or<(With<BoardTile>, With<Token>)>>,
+) {
+    if new_game_reader.iter().next().is_some() {
+        *setup = HoleSetup::Vacancy;
+    }
+
+    let event = match mouse_data_reader.iter().last() {
+        Some(event) => event,
+        None => return,
+    };
+
+    // The button is reported for as long as it is held, so only its first frame counts as a click.
+    let clicked = event.clicked && !*was_clicked;
+    *was_clicked = event.clicked;
+    if !clicked || *setup == HoleSetup::Done || puzzle_select.open {
+        return;
+    }
+
+    let position = match layout.try_from_pixel(event.pos.x, event.pos.y) {
+        Ok(position) => position,
+        Err(_) => return,
+    };
+    let hole = position.to_notation(Notation::Algebraic);
+
+    match *setup {
+        HoleSetup::Vacancy => match grid.with_vacancy(&position) {
+            Ok(fresh) => {
+                // Start over with a clean board, without the last game's tokens and highlights.
+                replace_board(
+                    &mut commands,
+                    &board,
+                    &materials,
+                    &mut layout,
+                    &mut grid,
+                    fresh,
+                );
+                *setup = HoleSetup::Finish;
+
+                info!("Starting with {} empty.", hole);
+            }
+            Err(error) => warn!("Cannot leave {} empty: {}", hole, error),
+        },
+        HoleSetup::Finish => match grid.with_finish(&position) {
+            Ok(finished) => {
+                *grid = finished;
+                *setup = HoleSetup::Done;
+
+                info!("Finishing in {}.", hole);
+            }
+            Err(error) => warn!("Cannot finish in {}: {}", hole, error),
+        },
+        HoleSetup::Done => {}
+    }
+}
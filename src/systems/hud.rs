@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+/**
+ * Shows the move counter in the window title. Jumps in a row by the same token count as one move,
+ * like in the published records. While the holes are being chosen, it shows what to click instead.
+ * It also shows the position class of the tokens and, once the class or the pagoda proves the goal
+ * out of reach, which of them does, and the move after which a single token became impossible. A
+ * lost game shows the verdict of its review. The puzzle-select screen keeps the title to itself.
+ */
+pub fn update_hud(
+    grid: Res<Grid>,
+    setup: Res<HoleSetup>,
+    feasibility: Res<Feasibility>,
+    solvability: Res<Solvability>,
+    game_review: Res<GameReview>,
+    puzzle_select: Res<PuzzleSelect>,
+    mut windows: ResMut<Windows>,
+) {
+    let changed = grid.is_changed()
+        || setup.is_changed()
+        || feasibility.is_changed()
+        || solvability.is_changed()
+        || game_review.is_changed()
+        || puzzle_select.is_changed();
+    if !changed || puzzle_select.open {
+        return;
+    }
+
+    let window = match windows.get_primary_mut() {
+        Some(window) => window,
+        None => return,
+    };
+
+    if let Some(prompt) = setup.prompt() {
+        window.set_title(format!("Steckhalma - {}", prompt));
+        return;
+    }
+
+    let mut title = format!(
+        "Steckhalma - Moves: {} - Tokens: {}",
+        grid.moves().len(),
+        grid.token_count()
+    );
+    if grid.finish != grid.center() {
+        title.push_str(&format!(
+            " - Finish: {}",
+            grid.finish.to_notation(Notation::Algebraic)
+        ));
+    }
+    if !grid.hints.is_empty() {
+        title.push_str(&format!(" - Hints: {}", grid.hints.len()));
+    }
+    if let Some((class, ruled_out)) = feasibility.class {
+        title.push_str(&format!(" - Class: {}", class));
+        if ruled_out {
+            title.push_str(" - Out of reach: class");
+        }
+    }
+    if let Some((value, needed)) = feasibility.pagoda {
+        title.push_str(&format!(" - Out of reach: pagoda {} < {}", value, needed));
+    }
+
+    // The mistake only counts while it is still part of the game.
+    if let Some((moves_played, mistake)) = solvability.mistake {
+        if grid.history.get(moves_played - 1) == Some(&mistake) {
+            title.push_str(&format!(
+                " - Mistake: move {}, {}",
+                moves_played,
+                mistake.to_notation(Notation::Algebraic)
+            ));
+        }
+    }
+
+    match &game_review.verdict {
+        Some((position, Review::Mistake { index, played, .. }))
+            if *position == grid.zobrist_hash() =>
+        {
+            title.push_str(&format!(
+                " - Review: move {}, {}, lost the game",
+                index + 1,
+                played.to_notation(Notation::Algebraic)
+            ));
+        }
+        Some((position, Review::UnreachableFromStart)) if *position == grid.zobrist_hash() => {
+            title.push_str(" - Review: lost from the start");
+        }
+        _ => {}
+    }
+
+    window.set_title(title);
+}
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+pub fn keyboard_input(
+    keys: Res<Input<KeyCode>>,
+    puzzle_select: Res<PuzzleSelect>,
+    mut history_writer: EventWriter<HistoryEvent>,
+    mut hint_writer: EventWriter<HintEvent>,
+    mut save_game_writer: EventWriter<SaveGameEvent>,
+    mut new_game_writer: EventWriter<NewGameEvent>,
+    mut puzzle_select_writer: EventWriter<PuzzleSelectEvent>,
+) {
+    if keys.just_pressed(KeyCode::P) {
+        puzzle_select_writer.send(PuzzleSelectEvent::Toggle);
+    }
+
+    // While the puzzle-select screen is open, the keys move through the list instead of playing.
+    if puzzle_select.open {
+        if keys.just_pressed(KeyCode::Up) || keys.just_pressed(KeyCode::Left) {
+            puzzle_select_writer.send(PuzzleSelectEvent::Previous);
+        }
+
+        if keys.just_pressed(KeyCode::Down) || keys.just_pressed(KeyCode::Right) {
+            puzzle_select_writer.send(PuzzleSelectEvent::Next);
+        }
+
+        if keys.just_pressed(KeyCode::Return) {
+            puzzle_select_writer.send(PuzzleSelectEvent::Play);
+        }
+
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::H) {
+        hint_writer.send(HintEvent);
+    }
+
+    if keys.just_pressed(KeyCode::N) {
+        new_game_writer.send(NewGameEvent);
+    }
+
+    let ctrl = keys.pressed(KeyCode::LControl) || keys.pressed(KeyCode::RControl);
+    if !ctrl {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::Z) {
+        history_writer.send(HistoryEvent::Undo);
+    }
+
+    if keys.just_pressed(KeyCode::Y) {
+        history_writer.send(HistoryEvent::Redo);
+    }
+
+    if keys.just_pressed(KeyCode::S) {
+        save_game_writer.send(SaveGameEvent::Save);
+    }
+
+    if keys.just_pressed(KeyCode::O) {
+        save_game_writer.send(SaveGameEvent::Load);
+    }
+}
@@ -1,11 +1,27 @@
+pub mod feasibility;
+pub mod game_over;
+pub mod hint;
+pub mod history;
+pub mod hole_setup;
+pub mod hud;
+pub mod keyboard_input;
 pub mod mouse_input;
 pub mod player_move;
 pub mod process_mouse_input;
+pub mod puzzle_select;
 pub mod render;
+pub mod save_game;
 pub mod setup;
+pub mod solvability;
 
 use crate::prelude::*;
 
+/**
+ * Positions a solver in the background searches before it gives up. That takes a few seconds and
+ * keeps its memory in check on boards too large to search through.
+ */
+pub const SOLVER_NODE_LIMIT: usize = 2_000_000;
+
 pub struct Materials {
     pub black: Handle<ColorMaterial>,
     pub hovered: Handle<ColorMaterial>,
@@ -14,4 +30,8 @@ pub struct Materials {
     pub token: Handle<ColorMaterial>,
     pub valid: Handle<ColorMaterial>,
     pub inbetween: Handle<ColorMaterial>,
+    pub hint: Handle<ColorMaterial>,
+    pub solvable: Handle<ColorMaterial>,
+    pub unsolvable: Handle<ColorMaterial>,
+    pub unknown: Handle<ColorMaterial>,
 }
@@ -11,7 +11,7 @@ pub fn mouse_input(
     }
 
     let position = wnd.cursor_position().unwrap();
-    let normalized = Grid::ui_to_grid(position.x, position.y);
+    let normalized = Layout::ui_to_grid(position.x, position.y);
 
     let clicked = mouse_button.pressed(MouseButton::Left);
 
@@ -3,6 +3,7 @@ use crate::prelude::*;
 pub fn player_move(
     mut commands: Commands,
     mut grid: ResMut<Grid>,
+    layout: Res<Layout>,
     mut move_reader: EventReader<ValidMoveEvent>,
     tokens: Query<(Entity, &Transform), With<Token>>,
 ) {
@@ -12,24 +13,64 @@ pub fn player_move(
     }
 
     let move_made = move_reader.unwrap().data;
-    grid.update_tile(&move_made.origin, false, false);
-    grid.update_tile(&move_made.middle, false, false);
-    grid.update_tile(&move_made.target, false, true);
+    if let Err(error) = grid.apply_move(&move_made) {
+        warn!("Rejected move: {}", error);
+        return;
+    }
+
+    // A token that jumps again right away continues its move, so log the whole move so far.
+    let moves = grid.moves();
+    if let Some(current) = moves.last() {
+        info!(
+            "Move {}: {}",
+            moves.len(),
+            current.to_notation(Notation::Algebraic)
+        );
+    }
+
+    move_token(
+        &mut commands,
+        &layout,
+        &tokens,
+        &move_made.origin,
+        &move_made.target,
+    );
+    despawn_token(&mut commands, &layout, &tokens, &move_made.middle);
+}
 
+pub fn move_token(
+    commands: &mut Commands,
+    layout: &Layout,
+    tokens: &Query<(Entity, &Transform), With<Token>>,
+    from: &Position,
+    to: &Position,
+) {
     for (entity, transform) in tokens.iter() {
-        let normalized = Grid::world_to_grid(transform.translation);
-        let position = Grid::from_pixel(normalized.x, normalized.y);
+        let normalized = Layout::world_to_grid(transform.translation);
+        let position = layout.from_pixel(normalized.x, normalized.y);
 
-        if move_made.origin == position {
-            let mut pixel_position = Grid::to_pixel(&move_made.target);
+        if *from == position {
+            let mut pixel_position = layout.to_pixel(to);
             pixel_position.z = 1.0;
 
             commands
                 .entity(entity)
                 .insert(Transform::from_translation(pixel_position));
         }
+    }
+}
+
+pub fn despawn_token(
+    commands: &mut Commands,
+    layout: &Layout,
+    tokens: &Query<(Entity, &Transform), With<Token>>,
+    at: &Position,
+) {
+    for (entity, transform) in tokens.iter() {
+        let normalized = Layout::world_to_grid(transform.translation);
+        let position = layout.from_pixel(normalized.x, normalized.y);
 
-        if move_made.middle == position {
+        if *at == position {
             commands.entity(entity).despawn();
         }
     }
@@ -1,15 +1,15 @@
 use crate::prelude::*;
 
-use crate::grid::game_move::*;
-use crate::grid::position::*;
-use crate::grid::tile::*;
-
 pub fn process_mouse_input(
     mut commands: Commands,
     mut mouse_data_reader: EventReader<MouseDataEvent>,
     mut move_event_writer: EventWriter<ValidMoveEvent>,
     grid: Res<Grid>,
+    layout: Res<Layout>,
+    setup: Res<HoleSetup>,
+    puzzle_select: Res<PuzzleSelect>,
     selected: Query<(Entity, &Transform), With<Selected>>,
+    hints: Query<Entity, With<Hint>>,
     board_tiles: Query<
         (
             Entity,
@@ -23,26 +23,32 @@ pub fn process_mouse_input(
     >,
 ) {
     let mouse_data_reader = mouse_data_reader.iter().next();
-    if mouse_data_reader.is_none() {
+    if mouse_data_reader.is_none() || *setup != HoleSetup::Done || puzzle_select.open {
         return;
     }
 
     let event = mouse_data_reader.unwrap();
+    if event.clicked {
+        clear_hint(&mut commands, &hints);
+    }
+
     let selected_entity = selected.iter().next();
     let mouse_pos = event.pos;
-    let mouse_grid_pos = Grid::from_pixel(mouse_pos.x, mouse_pos.y);
+    let mouse_grid_pos = layout.try_from_pixel(mouse_pos.x, mouse_pos.y).ok();
 
     let mut valid_moves: Option<ValidMoves> = None;
     if selected_entity.is_some() {
         let (_, selected_transform) = selected_entity.unwrap();
-        let normalized = Grid::world_to_grid(selected_transform.translation);
-        let tile = grid.tile_from_pixel(normalized.x, normalized.y);
+        let normalized = Layout::world_to_grid(selected_transform.translation);
+        let tile = grid.tile_from_position(&layout.from_pixel(normalized.x, normalized.y));
         valid_moves = Some(grid.calculate_valid_moves(&tile.position));
     }
 
     for (entity, transform, hovered, selected, valid, inbetween) in board_tiles.iter() {
-        let normalized = Grid::world_to_grid(transform.translation);
-        let tile = grid.tile_from_pixel(normalized.x, normalized.y).clone();
+        let normalized = Layout::world_to_grid(transform.translation);
+        let tile = grid
+            .tile_from_position(&layout.from_pixel(normalized.x, normalized.y))
+            .clone();
 
         remove_highlights(
             &mut commands,
@@ -107,12 +113,12 @@ fn remove_highlights(
 fn update_hover_and_selection(
     commands: &mut Commands,
     entity: &Entity,
-    mouse_grid_pos: &Position,
+    mouse_grid_pos: &Option<Position>,
     tile: &Tile,
     clicked: bool,
 ) {
     // Mouse is over tile
-    if *mouse_grid_pos == tile.position {
+    if *mouse_grid_pos == Some(tile.position) {
         // Hover
         commands.entity(*entity).insert(Hovered {});
 
@@ -129,7 +135,7 @@ fn check_move_and_highlights(
     tile: &Tile,
     valid_moves: &Option<ValidMoves>,
     clicked: bool,
-    mouse_grid_pos: &Position,
+    mouse_grid_pos: &Option<Position>,
     move_event_writer: &mut EventWriter<ValidMoveEvent>,
 ) {
     // Move related logic
@@ -141,7 +147,7 @@ fn check_move_and_highlights(
                     commands.entity(*entity).insert(ValidMoveComponent {});
 
                     // Make a move
-                    if clicked && *mouse_grid_pos == tile.position {
+                    if clicked && *mouse_grid_pos == Some(tile.position) {
                         move_event_writer.send(ValidMoveEvent {
                             data: valid_move.clone(),
                         });
@@ -0,0 +1,227 @@
+use std::path::Path;
+
+use crate::prelude::*;
+
+/**
+ * Monospaced font of the puzzle list, so its columns line up.
+ */
+pub const PUZZLE_FONT: &str = "fonts/DejaVuSansMono.ttf";
+
+// Number of puzzles listed at once. The list scrolls to keep the highlighted one in view.
+const VISIBLE_PUZZLES: usize = 16;
+
+/**
+ * The puzzle-select screen, opened with P. It lists every puzzle with its par and whether and in
+ * how many moves it was solved, on top of the game, which stays as it is until a puzzle is picked.
+ */
+#[derive(Default)]
+pub struct PuzzleSelect {
+    pub open: bool,
+    // Position of the highlighted puzzle in `PuzzlePacks::puzzles`.
+    pub index: usize,
+    pub font: Handle<Font>,
+}
+
+/**
+ * The puzzle being played, if any, to record its result once it is solved.
+ */
+pub struct CurrentPuzzle(pub Option<Puzzle>);
+
+pub fn setup_puzzles(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut select: ResMut<PuzzleSelect>,
+) {
+    let handles = asset_server
+        .load_folder(PUZZLE_FOLDER)
+        .unwrap_or_else(|error| {
+            warn!("Could not load the puzzle packs: {}", error);
+            Vec::new()
+        });
+
+    commands.insert_resource(PuzzlePacks { handles });
+    commands.insert_resource(PuzzleProgress::load(Path::new(DEFAULT_PROGRESS_PATH)));
+    select.font = asset_server.load(PUZZLE_FONT);
+}
+
+pub fn puzzle_select(
+    mut commands: Commands,
+    mut select: ResMut<PuzzleSelect>,
+    mut current: ResMut<CurrentPuzzle>,
+    mut grid: ResMut<Grid>,
+    mut layout: ResMut<Layout>,
+    materials: Res<Materials>,
+    packs: Res<PuzzlePacks>,
+    pack_assets: Res<Assets<PuzzlePack>>,
+    progress: Res<PuzzleProgress>,
+    mut select_reader: EventReader<PuzzleSelectEvent>,
+    mut windows: ResMut<Windows>,
+    board: Query<Entity, Or<(With<BoardTile>, With<Token>)>>,
+    list: Query<Entity, With<PuzzleList>>,
+) {
+    let event = select_reader.iter().next();
+    // Packs may finish loading while the list is shown.
+    if event.is_none() && !(select.open && pack_assets.is_changed()) {
+        return;
+    }
+
+    let puzzles = packs.puzzles(&pack_assets);
+    // A pack may have been removed since the index was picked.
+    if select.index >= puzzles.len() {
+        select.index = puzzles.len() - 1;
+    }
+
+    match event {
+        Some(PuzzleSelectEvent::Toggle) => select.open = !select.open,
+        _ if !select.open => return,
+        Some(PuzzleSelectEvent::Previous) => {
+            select.index = (select.index + puzzles.len() - 1) % puzzles.len();
+        }
+        Some(PuzzleSelectEvent::Next) => select.index = (select.index + 1) % puzzles.len(),
+        Some(PuzzleSelectEvent::Play) => {
+            let puzzle = puzzles[select.index].clone();
+            info!("Playing {}.", puzzle.name);
+
+            select.open = false;
+            replace_board(
+                &mut commands,
+                &board,
+                &materials,
+                &mut layout,
+                &mut grid,
+                puzzle.grid(),
+            );
+            current.0 = Some(puzzle);
+        }
+        None => {}
+    }
+
+    for entity in list.iter() {
+        commands.entity(entity).despawn();
+    }
+    if !select.open {
+        return;
+    }
+
+    spawn_puzzle_list(&mut commands, &select, &materials, &puzzles, &progress);
+    if let Some(window) = windows.get_primary_mut() {
+        window.set_title(format!(
+            "Steckhalma - Puzzle {}/{} - Up/Down, Enter to play, P to go back",
+            select.index + 1,
+            puzzles.len()
+        ));
+    }
+}
+
+/**
+ * Covers the board with the list of puzzles around the highlighted one.
+ */
+fn spawn_puzzle_list(
+    commands: &mut Commands,
+    select: &PuzzleSelect,
+    materials: &Materials,
+    puzzles: &[Puzzle],
+    progress: &PuzzleProgress,
+) {
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: materials.black.clone(),
+            sprite: Sprite::new(Vec2::new(WINDOW_SIZE, WINDOW_SIZE)),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.0, 5.0)),
+            ..Default::default()
+        })
+        .insert(PuzzleList {});
+
+    let first = select
+        .index
+        .saturating_sub(VISIBLE_PUZZLES / 2)
+        .min(puzzles.len().saturating_sub(VISIBLE_PUZZLES));
+    let sections = puzzles
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(VISIBLE_PUZZLES)
+        .map(|(index, puzzle)| {
+            let highlighted = index == select.index;
+            TextSection {
+                value: format!(
+                    "{}{} {:<16} {}",
+                    if index > first { "\n" } else { "" },
+                    if highlighted { ">" } else { " " },
+                    puzzle.name,
+                    puzzle_status(puzzle, progress)
+                ),
+                style: TextStyle {
+                    font: select.font.clone(),
+                    font_size: 18.0,
+                    color: if highlighted {
+                        Color::rgb(1.0, 1.0, 0.0)
+                    } else {
+                        Color::rgb(1.0, 1.0, 1.0)
+                    },
+                },
+            }
+        })
+        .collect();
+
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text {
+                sections,
+                alignment: TextAlignment {
+                    vertical: VerticalAlign::Center,
+                    horizontal: HorizontalAlign::Left,
+                },
+            },
+            transform: Transform::from_translation(Vec3::new(
+                -WINDOW_SIZE / 2.0 + PADDING * 2.0,
+                0.0,
+                6.0,
+            )),
+            ..Default::default()
+        })
+        .insert(PuzzleList {});
+}
+
+/**
+ * Whether the puzzle was solved and in how many moves, next to its par, e.g. `solved in 6, par 5`.
+ */
+fn puzzle_status(puzzle: &Puzzle, progress: &PuzzleProgress) -> String {
+    let solved = match progress.best(&puzzle.name) {
+        Some(best) => format!("solved in {}", best),
+        None => "unsolved".to_string(),
+    };
+
+    match puzzle.par {
+        Some(par) => format!("{}, par {}", solved, par),
+        None => solved,
+    }
+}
+
+/**
+ * Remembers the moves a puzzle was solved in, and how they compare to its par.
+ */
+pub fn record_puzzle_result(
+    grid: Res<Grid>,
+    current: Res<CurrentPuzzle>,
+    mut progress: ResMut<PuzzleProgress>,
+    mut game_over_reader: EventReader<GameOverEvent>,
+) {
+    for event in game_over_reader.iter() {
+        let puzzle = match &current.0 {
+            Some(puzzle) if grid.pattern.as_ref() == Some(&puzzle.goal) => puzzle,
+            _ => continue,
+        };
+        if event.result != GameResult::Perfect {
+            continue;
+        }
+
+        let moves = grid.moves().len();
+        if progress.record(&puzzle.name, moves) {
+            info!("New best for {}: {} moves.", puzzle.name, moves);
+        }
+        if let Some(par) = puzzle.par {
+            info!("{} solved in {} moves, par is {}.", puzzle.name, moves, par);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::prelude::*;
 pub fn render(
     mut commands: Commands,
     grid: Res<Grid>,
+    layout: Res<Layout>,
     mats: Res<Materials>,
     entities: Query<
         (
@@ -12,18 +13,25 @@ pub fn render(
             Option<&Hovered>,
             Option<&ValidMoveComponent>,
             Option<&InbetweenComponent>,
+            Option<&Hint>,
         ),
         With<BoardTile>,
     >,
 ) {
-    for (entity, transform, selected, hovered, valid, inbetween) in entities.iter() {
-        let normalized = Grid::world_to_grid(transform.translation);
-        let tile = grid.tile_from_pixel(normalized.x, normalized.y);
+    for (entity, transform, selected, hovered, valid, inbetween, hint) in entities.iter() {
+        let normalized = Layout::world_to_grid(transform.translation);
+        let tile = grid.tile_from_position(&layout.from_pixel(normalized.x, normalized.y));
         let selected = selected.is_some();
         let hovered = hovered.is_some();
         let valid = valid.is_some();
         let inbetween = inbetween.is_some();
 
+        // A hint is drawn with the usual highlights, only in its own colour.
+        if hint.is_some() && (selected || valid || inbetween) {
+            commands.entity(entity).insert(mats.hint.clone());
+            continue;
+        }
+
         let color = match (selected, hovered, valid, tile, inbetween) {
             // Selected
             (selected, _, _, tile, _) if selected && tile.has_token => mats.selected.clone(),
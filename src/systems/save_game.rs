@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+pub fn save_game(
+    mut commands: Commands,
+    options: Res<Options>,
+    mut grid: ResMut<Grid>,
+    mut layout: ResMut<Layout>,
+    mut current_puzzle: ResMut<CurrentPuzzle>,
+    materials: Res<Materials>,
+    mut save_game_reader: EventReader<SaveGameEvent>,
+    board: Query<Entity, Or<(With<BoardTile>, With<Token>)>>,
+) {
+    let save_game_reader = save_game_reader.iter().next();
+    if save_game_reader.is_none() {
+        return;
+    }
+
+    let path = &options.save_path;
+    match save_game_reader.unwrap() {
+        SaveGameEvent::Save => match grid.save(path) {
+            Ok(()) => info!("Saved game to {}", path.display()),
+            Err(error) => warn!("Could not save {}: {}", path.display(), error),
+        },
+        SaveGameEvent::Load => match Grid::load(path) {
+            Ok(loaded) => {
+                replace_board(
+                    &mut commands,
+                    &board,
+                    &materials,
+                    &mut layout,
+                    &mut grid,
+                    loaded,
+                );
+                // Saved games do not record which puzzle they came from.
+                current_puzzle.0 = None;
+
+                info!("Loaded game from {}", path.display());
+            }
+            Err(error) => warn!("Could not load {}: {}", path.display(), error),
+        },
+    }
+}
@@ -10,10 +10,37 @@ pub fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>
         token: materials.add(Color::rgb(0.75, 0.0, 0.0).into()),
         valid: materials.add(Color::rgb(1.0, 1.0, 0.0).into()),
         inbetween: materials.add(Color::rgb(1.0, 0.5, 0.0).into()),
+        hint: materials.add(Color::rgb(0.0, 1.0, 1.0).into()),
+        solvable: materials.add(Color::rgb(0.0, 0.75, 0.0).into()),
+        unsolvable: materials.add(Color::rgb(1.0, 0.0, 0.0).into()),
+        unknown: materials.add(Color::rgb(1.0, 1.0, 0.0).into()),
     });
 }
 
-pub fn setup_board(mut commands: Commands, grid: Res<Grid>, materials: Res<Materials>) {
+pub fn setup_board(
+    mut commands: Commands,
+    grid: Res<Grid>,
+    layout: Res<Layout>,
+    materials: Res<Materials>,
+) {
+    spawn_board(&mut commands, &grid, &layout, &materials);
+
+    // Spawn the solvability indicator in the top left corner of the window.
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: materials.tile.clone(),
+            sprite: Sprite::new(Vec2::new(PADDING * 2.0, PADDING * 2.0)),
+            transform: Transform::from_translation(Vec3::new(
+                -WINDOW_SIZE / 2.0 + PADDING * 2.0,
+                WINDOW_SIZE / 2.0 - PADDING * 2.0,
+                2.0,
+            )),
+            ..Default::default()
+        })
+        .insert(SolvabilityIndicator {});
+}
+
+pub fn spawn_board(commands: &mut Commands, grid: &Grid, layout: &Layout, materials: &Materials) {
     for tile in grid.tiles.iter() {
         let mat = if tile.is_corner {
             materials.black.clone()
@@ -25,8 +52,11 @@ pub fn setup_board(mut commands: Commands, grid: Res<Grid>, materials: Res<Mater
         commands
             .spawn_bundle(SpriteBundle {
                 material: mat,
-                sprite: Sprite::new(Vec2::new(TILE_SIZE_PADDED, TILE_SIZE_PADDED)),
-                transform: Transform::from_translation(Grid::to_pixel(&tile.position)),
+                sprite: Sprite::new(Vec2::new(
+                    layout.tile_size_padded(),
+                    layout.tile_size_padded(),
+                )),
+                transform: Transform::from_translation(layout.to_pixel(&tile.position)),
                 ..Default::default()
             })
             .insert(BoardTile {});
@@ -36,14 +66,44 @@ pub fn setup_board(mut commands: Commands, grid: Res<Grid>, materials: Res<Mater
         }
 
         // Spawn tokens.
-        let token = Token::from_position(&tile.position);
-        commands
-            .spawn_bundle(SpriteBundle {
-                material: materials.token.clone(),
-                sprite: Sprite::new(Vec2::new(token.width, token.height)),
-                transform: Transform::from_translation(Vec3::new(token.x, token.y, 1.0)),
-                ..Default::default()
-            })
-            .insert(token);
+        spawn_token(commands, layout, materials, &tile.position);
     }
 }
+
+/**
+ * Replaces the game with another one, which may be on a board of a different size, and rebuilds
+ * the board on screen from scratch.
+ */
+pub fn replace_board(
+    commands: &mut Commands,
+    board: &Query<Entity, Or<(With<BoardTile>, With<Token>)>>,
+    materials: &Materials,
+    layout: &mut Layout,
+    grid: &mut Grid,
+    replacement: Grid,
+) {
+    for entity in board.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    *layout = Layout::new(&replacement);
+    spawn_board(commands, &replacement, layout, materials);
+    *grid = replacement;
+}
+
+pub fn spawn_token(
+    commands: &mut Commands,
+    layout: &Layout,
+    materials: &Materials,
+    position: &Position,
+) {
+    let token = Token::from_position(position, layout);
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: materials.token.clone(),
+            sprite: Sprite::new(Vec2::new(token.width, token.height)),
+            transform: Transform::from_translation(Vec3::new(token.x, token.y, 1.0)),
+            ..Default::default()
+        })
+        .insert(token);
+}
@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use futures_lite::future;
+
+use crate::prelude::*;
+
+/**
+ * A solver running in the background for the position reached after `moves_played` moves. It
+ * fails for boards too large to search, and gives `None` once it runs out of positions to search.
+ */
+pub struct SolvabilityTask {
+    pub moves_played: usize,
+    pub task: Task<Result<Option<bool>, GridError>>,
+    // Stops the solver once the position is left.
+    pub cancel: Arc<AtomicBool>,
+}
+
+/**
+ * The move after which a single token could no longer be reached, as the number of moves played
+ * with it and the move itself, shown in the HUD.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Solvability {
+    pub mistake: Option<(usize, ValidMove)>,
+}
+
+/**
+ * Starts a solver in the background whenever the tokens move, cancelling the check of the previous
+ * position, and greys out the indicator until it is done.
+ */
+pub fn check_solvability(
+    mut commands: Commands,
+    grid: Res<Grid>,
+    materials: Res<Materials>,
+    thread_pool: Res<AsyncComputeTaskPool>,
+    mut checked: Local<Option<u64>>,
+    tasks: Query<(Entity, &SolvabilityTask)>,
+    indicators: Query<Entity, With<SolvabilityIndicator>>,
+) {
+    if !grid.is_changed() || *checked == Some(grid.zobrist_hash()) {
+        return;
+    }
+    *checked = Some(grid.zobrist_hash());
+
+    for (entity, check) in tasks.iter() {
+        check.cancel.store(true, Ordering::Relaxed);
+        commands.entity(entity).despawn();
+    }
+
+    for entity in indicators.iter() {
+        commands.entity(entity).insert(materials.tile.clone());
+    }
+
+    let position = grid.clone();
+    let goal = Goal::for_grid(&grid).relaxed();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+    let task = thread_pool.spawn(async move {
+        let mut solver = Solver::new(goal)
+            .with_cancel(flag)
+            .with_node_limit(SOLVER_NODE_LIMIT);
+        let solution = solver.solve(&position);
+
+        solution.map(|solution| match solution {
+            Some(_) => Some(true),
+            None if solver.is_out_of_budget() => None,
+            None => Some(false),
+        })
+    });
+
+    commands.spawn().insert(SolvabilityTask {
+        moves_played: grid.history.len(),
+        task,
+        cancel,
+    });
+}
+
+/**
+ * Colours the indicator once the background solver is done, or in its own colour when the solver
+ * gave up, and names the move that made a single token impossible.
+ */
+pub fn update_solvability(
+    mut commands: Commands,
+    grid: Res<Grid>,
+    materials: Res<Materials>,
+    mut solvability: ResMut<Solvability>,
+    mut last_result: Local<Option<(usize, bool)>>,
+    mut tasks: Query<(Entity, &mut SolvabilityTask)>,
+    indicators: Query<Entity, With<SolvabilityIndicator>>,
+) {
+    for (entity, mut check) in tasks.iter_mut() {
+        // A cancelled solver gives up with a wrong answer, and is despawned at the end of the frame.
+        if check.cancel.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let solvable = match future::block_on(future::poll_once(&mut check.task)) {
+            Some(solvable) => solvable,
+            None => continue,
+        };
+        commands.entity(entity).despawn();
+
+        // The indicator stays grey on boards the solver cannot search.
+        let solvable = match solvable {
+            Ok(Some(solvable)) => solvable,
+            Ok(None) => {
+                info!(
+                    "Gave up checking solvability after {} positions.",
+                    SOLVER_NODE_LIMIT
+                );
+                for indicator in indicators.iter() {
+                    commands.entity(indicator).insert(materials.unknown.clone());
+                }
+
+                // A mistake needs a known result before it.
+                *last_result = None;
+                continue;
+            }
+            Err(error) => {
+                warn!("Cannot check solvability: {}", error);
+                continue;
+            }
+        };
+
+        let material = if solvable {
+            materials.solvable.clone()
+        } else {
+            materials.unsolvable.clone()
+        };
+        for indicator in indicators.iter() {
+            commands.entity(indicator).insert(material.clone());
+        }
+
+        let moves_played = check.moves_played;
+        if !solvable && moves_played > 0 && *last_result == Some((moves_played - 1, true)) {
+            if let Some(mistake) = grid.history.get(moves_played - 1) {
+                info!(
+                    "Move {}: {} was the mistake, a single token can no longer be reached.",
+                    moves_played,
+                    mistake.to_notation(Notation::Algebraic)
+                );
+                solvability.mistake = Some((moves_played, *mistake));
+            }
+        }
+        if solvable && solvability.mistake.is_some() {
+            solvability.mistake = None;
+        }
+
+        *last_result = Some((moves_played, solvable));
+    }
+}
@@ -0,0 +1,55 @@
+use std::env;
+use std::process;
+
+use steckhalma::prelude::*;
+
+/**
+ * Prints the complement table of the boards named on the command line, or of every classic board.
+ *
+ * ```text
+ * cargo run --release --bin complements -- english french
+ * ```
+ */
+fn main() {
+    let names: Vec<String> = env::args().skip(1).collect();
+    let variants: Vec<BoardVariant> = if names.is_empty() {
+        BoardVariant::ALL.to_vec()
+    } else {
+        names
+            .iter()
+            .map(|name| {
+                BoardVariant::from_name(name).unwrap_or_else(|| {
+                    let known: Vec<&str> = BoardVariant::ALL
+                        .iter()
+                        .map(|variant| variant.name())
+                        .collect();
+                    eprintln!(
+                        "Unknown board {:?}, expected one of {}",
+                        name,
+                        known.join(", ")
+                    );
+                    process::exit(2);
+                })
+            })
+            .collect()
+    };
+
+    for (index, variant) in variants.iter().enumerate() {
+        let table = ComplementTable::new(&Grid::from_variant(*variant)).unwrap_or_else(|error| {
+            eprintln!("Cannot solve the {} board: {}", variant.name(), error);
+            process::exit(1);
+        });
+        let complements: Vec<String> = table
+            .complements()
+            .iter()
+            .map(|position| position.to_notation(Notation::Algebraic))
+            .collect();
+
+        if index > 0 {
+            println!();
+        }
+        println!("{}", variant.name());
+        println!("{}", table);
+        println!("complements: {}", complements.join(" "));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::prelude::*;
+
+/**
+ * Compact position for fast search: bit `row * width + col` is set when that hole holds a token.
+ * A `u128` covers every board up to 11x11, which includes all the classic variants.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bitboard(pub u128);
+
+/**
+ * A single jump between bit indices of a `Bitboard`.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jump {
+    pub origin: usize,
+    pub middle: usize,
+    pub target: usize,
+}
+
+impl Jump {
+    pub fn mask(&self) -> u128 {
+        (1 << self.origin) | (1 << self.middle) | (1 << self.target)
+    }
+}
+
+/**
+ * The board a `Bitboard` lives on, together with the masks used for move generation.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitboardLayout {
+    pub variant: Option<BoardVariant>,
+    pub width: usize,
+    pub height: usize,
+    pub holes: u128,
+    // Holes a token can jump from in each direction, in the order left, right, up, down.
+    origins: [u128; 4],
+}
+
+impl BitboardLayout {
+    pub const MAX_TILES: usize = 128;
+
+    /**
+     * Fails for boards with more tiles than fit into a `Bitboard`.
+     */
+    pub fn new(grid: &Grid) -> Result<Self, GridError> {
+        if grid.tiles.len() > BitboardLayout::MAX_TILES {
+            return Err(GridError::TooLarge {
+                tiles: grid.tiles.len(),
+            });
+        }
+
+        let mut layout = Self {
+            variant: grid.variant,
+            width: grid.width,
+            height: grid.height,
+            holes: 0,
+            origins: [0; 4],
+        };
+
+        for tile in grid.tiles.iter().filter(|tile| !tile.is_corner) {
+            layout.holes |= 1 << grid.to_index(&tile.position);
+        }
+
+        let (width, height) = (grid.width, grid.height);
+        for index in 0..(width * height) {
+            let (row, col) = (index / width, index % width);
+            let candidates = [
+                (col >= 2).then(|| (index - 1, index - 2)),
+                (col + 2 < width).then(|| (index + 1, index + 2)),
+                (row >= 2).then(|| (index - width, index - 2 * width)),
+                (row + 2 < height).then(|| (index + width, index + 2 * width)),
+            ];
+
+            for (origins, candidate) in layout.origins.iter_mut().zip(candidates) {
+                if let Some((middle, target)) = candidate {
+                    let jump = Jump {
+                        origin: index,
+                        middle,
+                        target,
+                    };
+
+                    if jump.mask() & layout.holes == jump.mask() {
+                        *origins |= 1 << index;
+                    }
+                }
+            }
+        }
+
+        Ok(layout)
+    }
+
+    pub fn to_bitboard(&self, grid: &Grid) -> Bitboard {
+        let mut board = 0;
+        for (index, tile) in grid.tiles.iter().enumerate() {
+            if tile.has_token && !tile.is_corner {
+                board |= 1 << index;
+            }
+        }
+
+        Bitboard(board)
+    }
+
+    pub fn to_grid(&self, board: Bitboard) -> Grid {
+        let holes: Vec<bool> = (0..(self.width * self.height))
+            .map(|index| self.holes & (1 << index) != 0)
+            .collect();
+
+        let mut grid = Grid::from_mask(self.width, self.height, &holes);
+        grid.variant = self.variant;
+        for (index, is_hole) in holes.iter().enumerate() {
+            if *is_hole && board.0 & (1 << index) == 0 {
+                let position = grid.from_index(index);
+                grid.update_tile(&position, false, false);
+            }
+        }
+
+        grid
+    }
+
+    /**
+     * Every legal jump in the position, in the same order as `Grid::all_valid_moves`.
+     */
+    pub fn jumps(&self, board: Bitboard) -> Vec<Jump> {
+        let pegs = board.0;
+        let width = self.width as u32;
+        let empty = self.holes & !pegs;
+
+        let movable = [
+            // Left: the middle and the target are one and two columns before the origin.
+            pegs & (pegs << 1) & (empty << 2) & self.origins[0],
+            // Right
+            pegs & (pegs >> 1) & (empty >> 2) & self.origins[1],
+            // Up
+            pegs & shl(pegs, width) & shl(empty, 2 * width) & self.origins[2],
+            // Down
+            pegs & shr(pegs, width) & shr(empty, 2 * width) & self.origins[3],
+        ];
+
+        let mut jumps = Vec::new();
+        let mut origins = movable[0] | movable[1] | movable[2] | movable[3];
+        while origins != 0 {
+            let origin = origins.trailing_zeros() as usize;
+            origins &= origins - 1;
+
+            for (direction, movable) in movable.iter().enumerate() {
+                if movable & (1 << origin) == 0 {
+                    continue;
+                }
+
+                let step = if direction < 2 { 1 } else { self.width };
+                let (middle, target) = if direction % 2 == 0 {
+                    (origin - step, origin - 2 * step)
+                } else {
+                    (origin + step, origin + 2 * step)
+                };
+
+                jumps.push(Jump {
+                    origin,
+                    middle,
+                    target,
+                });
+            }
+        }
+
+        jumps
+    }
+
+    /**
+     * Holes that no jump ever passes over. A token in one of them only leaves by jumping itself.
+     */
+    pub fn unjumpable(&self) -> u128 {
+        let width = self.width as u32;
+        let middles = (self.origins[0] >> 1)
+            | (self.origins[1] << 1)
+            | shr(self.origins[2], width)
+            | shl(self.origins[3], width);
+
+        self.holes & !middles
+    }
+
+    /**
+     * Regions of holes that are never jumped over from outside, known as Merson regions: a jump
+     * over one of their holes starts or lands inside the region. While such a region is full, no
+     * jump can reach into it, so emptying it takes a move starting inside. These are the holes
+     * that cannot be jumped over, then every 2x2 square of the other holes, as every line through
+     * a hole of a square goes on to another hole of the square. Squares may overlap each other.
+     */
+    pub fn merson_regions(&self) -> Vec<u128> {
+        let unjumpable = self.unjumpable();
+        let mut regions: Vec<u128> = (0..BitboardLayout::MAX_TILES)
+            .filter(|index| unjumpable & (1 << index) != 0)
+            .map(|index| 1 << index)
+            .collect();
+
+        for index in 0..(self.width * self.height) {
+            let (row, col) = (index / self.width, index % self.width);
+            if row + 1 >= self.height || col + 1 >= self.width {
+                continue;
+            }
+
+            let below = index + self.width;
+            let square = 1 << index | 1 << (index + 1) | 1 << below | 1 << (below + 1);
+            if square & self.holes & !unjumpable == square {
+                regions.push(square);
+            }
+        }
+
+        regions
+    }
+
+    pub fn apply(&self, board: Bitboard, jump: &Jump) -> Bitboard {
+        Bitboard(board.0 ^ jump.mask())
+    }
+
+    pub fn to_position(&self, index: usize) -> Position {
+        Position {
+            row: index / self.width,
+            col: index % self.width,
+        }
+    }
+
+    pub fn to_valid_move(&self, jump: &Jump) -> ValidMove {
+        ValidMove {
+            origin: self.to_position(jump.origin),
+            middle: self.to_position(jump.middle),
+            target: self.to_position(jump.target),
+        }
+    }
+}
+
+impl Bitboard {
+    pub fn token_count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+}
+
+// Shifts by a row on boards 64 or more holes wide can move every bit out of a `u128`, which plain
+// shifts do not allow.
+fn shl(bits: u128, shift: u32) -> u128 {
+    bits.checked_shl(shift).unwrap_or(0)
+}
+
+fn shr(bits: u128, shift: u32) -> u128 {
+    bits.checked_shr(shift).unwrap_or(0)
+}
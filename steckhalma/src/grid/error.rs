@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JumpError {
+    // Origin, middle and target are not three neighbouring holes in a straight line.
+    NotAJump,
+    NoTokenAtOrigin,
+    NoTokenToJumpOver,
+    TargetOccupied,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridError {
+    // The coordinates lie outside of the grid. They are signed, as pixels left of or above the
+    // board map to negative rows and columns.
+    OffBoard {
+        row: i64,
+        col: i64,
+    },
+    // The position is on the grid, but is one of the corners without a hole.
+    NotAHole(Position),
+    IllegalJump {
+        jump: ValidMove,
+        reason: JumpError,
+    },
+    // A text board contains something other than `o`, `.` or a space.
+    InvalidCharacter {
+        row: usize,
+        col: usize,
+        character: char,
+    },
+    // A text board contains no rows.
+    EmptyBoard,
+    // The board has more tiles than a bitboard holds, so the solver cannot search it.
+    TooLarge {
+        tiles: usize,
+    },
+    // A cross board needs an odd size of at least 3 for its arms to meet in the center.
+    CrossSize {
+        size: usize,
+    },
+}
+
+impl fmt::Display for JumpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JumpError::NotAJump => write!(f, "the positions do not form a jump"),
+            JumpError::NoTokenAtOrigin => write!(f, "there is no token to move"),
+            JumpError::NoTokenToJumpOver => write!(f, "there is no token to jump over"),
+            JumpError::TargetOccupied => write!(f, "the target hole is not empty"),
+        }
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::OffBoard { row, col } => {
+                write!(f, "row {}, col {} is off the board", row, col)
+            }
+            GridError::NotAHole(position) => write!(
+                f,
+                "row {}, col {} is not a hole",
+                position.row, position.col
+            ),
+            GridError::IllegalJump { jump, reason } => write!(
+                f,
+                "illegal jump from row {}, col {} to row {}, col {}: {}",
+                jump.origin.row, jump.origin.col, jump.target.row, jump.target.col, reason
+            ),
+            GridError::InvalidCharacter {
+                row,
+                col,
+                character,
+            } => write!(
+                f,
+                "unexpected character {:?} at row {}, col {}",
+                character, row, col
+            ),
+            GridError::EmptyBoard => write!(f, "the board is empty"),
+            GridError::TooLarge { tiles } => write!(
+                f,
+                "the board has {} tiles, the solver supports up to {}",
+                tiles,
+                BitboardLayout::MAX_TILES
+            ),
+            GridError::CrossSize { size } => {
+                write!(f, "a cross needs an odd size of at least 3, not {}", size)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    // The board drawn in the file could not be read.
+    Grid(GridError),
+    MissingField(&'static str),
+    UnknownBoard(String),
+    // A hint was recorded at a move number that is not a number or beyond the history.
+    InvalidHint(String),
+    // The finish hole is not a position or not a hole of the board.
+    InvalidFinish(String),
+    // The goal pattern lists something other than holes of the board.
+    InvalidGoal(String),
+    Notation(NotationError),
+    // The recorded moves cannot have led to the saved position.
+    InconsistentHistory(ValidMove),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(error) => write!(f, "{}", error),
+            SaveError::Grid(error) => write!(f, "invalid board: {}", error),
+            SaveError::MissingField(field) => write!(f, "missing field {:?}", field),
+            SaveError::UnknownBoard(name) => write!(f, "unknown board {:?}", name),
+            SaveError::InvalidHint(text) => write!(f, "invalid hint move number {:?}", text),
+            SaveError::InvalidFinish(text) => write!(f, "invalid finish hole {:?}", text),
+            SaveError::InvalidGoal(text) => write!(f, "invalid goal hole {:?}", text),
+            SaveError::Notation(error) => write!(f, "invalid history: {}", error),
+            SaveError::InconsistentHistory(jump) => write!(
+                f,
+                "the move from row {}, col {} to row {}, col {} does not match the board",
+                jump.origin.row, jump.origin.col, jump.target.row, jump.target.col
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(error: std::io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+
+impl From<GridError> for SaveError {
+    fn from(error: GridError) -> Self {
+        SaveError::Grid(error)
+    }
+}
+
+impl From<NotationError> for SaveError {
+    fn from(error: NotationError) -> Self {
+        SaveError::Notation(error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotationError {
+    InvalidPosition(String),
+    // Origin and target of a move are not two holes apart in a straight line.
+    NotAJump(String),
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotationError::InvalidPosition(text) => write!(f, "invalid hole {:?}", text),
+            NotationError::NotAJump(text) => write!(f, "{:?} is not a jump", text),
+        }
+    }
+}
+
+impl std::error::Error for NotationError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PuzzleError {
+    // A drawn pattern could not be read.
+    Grid(GridError),
+    // A pattern does not have one entry per position of the board.
+    WrongSize {
+        expected: usize,
+        found: usize,
+    },
+    // A pattern puts a token outside of the board's holes.
+    NotAHole(Position),
+    // A drawn pattern has a hole where the board has none, or the other way around.
+    WrongBoard(Position),
+    MissingField(&'static str),
+    UnknownBoard(String),
+    // The par is not a number of moves.
+    InvalidPar(String),
+    Notation(NotationError),
+    // The reference solution plays a move that is not possible at that point.
+    IllegalSolution(GridError),
+    // The reference solution runs out of moves before reaching the goal.
+    IncompleteSolution,
+    // Something is wrong with the named puzzle of a pack.
+    InPuzzle {
+        name: String,
+        error: Box<PuzzleError>,
+    },
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PuzzleError::Grid(error) => write!(f, "invalid pattern: {}", error),
+            PuzzleError::WrongSize { expected, found } => write!(
+                f,
+                "the pattern has {} positions instead of {}",
+                found, expected
+            ),
+            PuzzleError::NotAHole(position) => write!(
+                f,
+                "the token at row {}, col {} is not in a hole",
+                position.row, position.col
+            ),
+            PuzzleError::WrongBoard(position) => write!(
+                f,
+                "the drawing does not match the board at row {}, col {}",
+                position.row, position.col
+            ),
+            PuzzleError::MissingField(field) => write!(f, "missing field {:?}", field),
+            PuzzleError::UnknownBoard(name) => write!(f, "unknown board {:?}", name),
+            PuzzleError::InvalidPar(text) => write!(f, "invalid par {:?}", text),
+            PuzzleError::Notation(error) => write!(f, "invalid solution: {}", error),
+            PuzzleError::IllegalSolution(error) => write!(f, "invalid solution: {}", error),
+            PuzzleError::IncompleteSolution => write!(f, "the solution does not reach the goal"),
+            PuzzleError::InPuzzle { name, error } => write!(f, "puzzle {:?}: {}", name, error),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+impl From<GridError> for PuzzleError {
+    fn from(error: GridError) -> Self {
+        PuzzleError::Grid(error)
+    }
+}
+
+impl From<NotationError> for PuzzleError {
+    fn from(error: NotationError) -> Self {
+        PuzzleError::Notation(error)
+    }
+}
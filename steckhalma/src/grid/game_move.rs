@@ -0,0 +1,105 @@
+use crate::prelude::*;
+
+pub enum MoveDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidMove {
+    pub origin: Position,
+    pub middle: Position,
+    pub target: Position,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidMoves {
+    pub list: Vec<ValidMove>,
+}
+
+impl ValidMove {
+    /**
+     * Builds the jump from origin to target, if they are two holes apart in a straight line.
+     */
+    pub fn between(origin: &Position, target: &Position) -> Option<Self> {
+        let horizontal = origin.row == target.row
+            && (origin.col + 2 == target.col || target.col + 2 == origin.col);
+        let vertical = origin.col == target.col
+            && (origin.row + 2 == target.row || target.row + 2 == origin.row);
+
+        if !horizontal && !vertical {
+            return None;
+        }
+
+        Some(ValidMove {
+            origin: *origin,
+            middle: Position {
+                row: (origin.row + target.row) / 2,
+                col: (origin.col + target.col) / 2,
+            },
+            target: *target,
+        })
+    }
+}
+
+/**
+ * One move as solitaire records count them: a token jumping once, or several times in a row.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiJump {
+    pub jumps: Vec<ValidMove>,
+}
+
+impl MultiJump {
+    pub fn new(jump: ValidMove) -> Self {
+        Self { jumps: vec![jump] }
+    }
+
+    pub fn origin(&self) -> Position {
+        self.jumps[0].origin
+    }
+
+    pub fn target(&self) -> Position {
+        self.jumps[self.jumps.len() - 1].target
+    }
+
+    /**
+     * Whether the jump is made by the token that made this move, so it belongs to it.
+     */
+    pub fn continues_with(&self, jump: &ValidMove) -> bool {
+        jump.origin == self.target()
+    }
+
+    /**
+     * Adds the jump to the move if it continues it, and returns whether it did.
+     */
+    pub fn extend(&mut self, jump: ValidMove) -> bool {
+        if !self.continues_with(&jump) {
+            return false;
+        }
+
+        self.jumps.push(jump);
+        true
+    }
+
+    /**
+     * Splits a sequence of jumps into moves, joining consecutive jumps by the same token.
+     */
+    pub fn group(jumps: &[ValidMove]) -> Vec<MultiJump> {
+        let mut moves: Vec<MultiJump> = Vec::new();
+
+        for jump in jumps.iter() {
+            let extended = moves
+                .last_mut()
+                .is_some_and(|multi_jump| multi_jump.extend(*jump));
+
+            if !extended {
+                moves.push(MultiJump::new(*jump));
+            }
+        }
+
+        moves
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameResult {
+    // The goal is reached: a single token is left in the finish hole, see `Grid::finish`, or the
+    // tokens form the puzzle's pattern.
+    Perfect,
+    // A single token is left outside of the finish hole, or short of the puzzle's pattern.
+    SinglePeg(Position),
+    // No moves are left, but more than one token is still on the board.
+    Stranded(usize),
+}
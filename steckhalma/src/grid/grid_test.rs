@@ -0,0 +1,812 @@
+use super::*;
+
+#[test]
+fn test_is_corner() {
+    let grid = Grid::new();
+
+    assert!(grid.is_corner(&Position {row: 0, col: 0}));
+    assert!(grid.is_corner(&Position {row: 0, col: 5}));
+    assert!(grid.is_corner(&Position {row: 5, col: 0}));
+    assert!(grid.is_corner(&Position {row: 5, col: 5}));
+    assert!(grid.is_corner(&Position {row: 7, col: 3}));
+    assert!(!grid.is_corner(&Position {row: 3, col: 3}));
+}
+
+#[test]
+fn test_from_index() {
+    let grid = Grid::new();
+
+    assert_eq!(grid.from_index(0), Position {row: 0, col: 0});
+    assert_eq!(grid.from_index(6), Position {row: 0, col: 6});
+    assert_eq!(grid.from_index(24), Position {row: 3, col: 3});
+    assert_eq!(grid.from_index(42), Position {row: 6, col: 0});
+    assert_eq!(grid.from_index(48), Position {row: 6, col: 6});
+    assert_eq!(grid.to_index(&Position {row: 3, col: 3}), 24);
+}
+
+#[test]
+fn test_cross() {
+    let small = Grid::cross(5).unwrap();
+    assert_eq!((small.width, small.height), (5, 5));
+    assert_eq!(small.tiles.iter().filter(|tile| !tile.is_corner).count(), 21);
+    assert_eq!(small.token_count(), 20);
+    assert!(small.is_corner(&Position {row: 0, col: 0}));
+    assert!(!small.is_corner(&Position {row: 0, col: 1}));
+
+    let large = Grid::cross(9).unwrap();
+    assert_eq!(large.tiles.iter().filter(|tile| !tile.is_corner).count(), 45);
+    assert_eq!(large.center(), Position {row: 4, col: 4});
+    assert!(!large.tile_from_position(&large.center()).has_token);
+    assert_eq!(large.all_valid_moves().list.len(), 4);
+    assert_eq!(Grid::cross(3).unwrap().token_count(), 8);
+
+    assert_eq!(Grid::cross(0).err(), Some(GridError::CrossSize {size: 0}));
+    assert_eq!(Grid::cross(1).err(), Some(GridError::CrossSize {size: 1}));
+    assert_eq!(Grid::cross(8).err(), Some(GridError::CrossSize {size: 8}));
+    assert_eq!(GridError::CrossSize {size: 8}.to_string(), "a cross needs an odd size of at least 3, not 8");
+}
+
+#[test]
+fn test_from_mask() {
+    let grid = Grid::from_mask(3, 2, &[true, true, false, false, true, true]);
+
+    assert_eq!(grid.token_count(), 4);
+    assert!(grid.is_corner(&Position {row: 0, col: 2}));
+    assert!(grid.is_corner(&Position {row: 1, col: 0}));
+    assert_eq!(grid.try_tile_from_position(&Position {row: 2, col: 0}), Err(GridError::OffBoard {row: 2, col: 0}));
+}
+
+#[test]
+fn test_undo_redo() {
+    let mut grid = Grid::new();
+    let valid_move = grid.calculate_valid_move(&Position {row: 1, col: 3}, MoveDirection::Down).unwrap();
+
+    grid.apply_move(&valid_move).unwrap();
+    assert!(!grid.tile_from_position(&valid_move.origin).has_token);
+    assert!(!grid.tile_from_position(&valid_move.middle).has_token);
+    assert!(grid.tile_from_position(&valid_move.target).has_token);
+
+    assert_eq!(grid.undo(), Some(valid_move));
+    assert_eq!(grid.undo(), None);
+    assert_eq!(grid.tiles, Grid::new().tiles);
+
+    assert_eq!(grid.redo(), Some(valid_move));
+    assert_eq!(grid.redo(), None);
+    assert_eq!(grid.history, vec![valid_move]);
+    assert!(grid.tile_from_position(&valid_move.target).has_token);
+}
+
+#[test]
+fn test_apply_move_clears_redo() {
+    let mut grid = Grid::new();
+    let first = grid.calculate_valid_move(&Position {row: 1, col: 3}, MoveDirection::Down).unwrap();
+    let second = grid.calculate_valid_move(&Position {row: 3, col: 1}, MoveDirection::Right).unwrap();
+
+    grid.apply_move(&first).unwrap();
+    grid.undo();
+    grid.apply_move(&second).unwrap();
+
+    assert_eq!(grid.redo(), None);
+    assert_eq!(grid.history, vec![second]);
+}
+
+
+#[test]
+fn test_game_result() {
+    let parse = |text: &str| text.parse::<Grid>().unwrap();
+    assert_eq!(Grid::new().game_result(), None);
+
+    let perfect = parse("
+  ...
+  ...
+.......
+...o...
+.......
+  ...
+  ...
+");
+    assert_eq!(perfect.game_result(), Some(GameResult::Perfect));
+
+    let single = parse("
+  o..
+  ...
+.......
+.......
+.......
+  ...
+  ...
+");
+    assert_eq!(single.game_result(), Some(GameResult::SinglePeg(Position {row: 0, col: 2})));
+
+    let stranded = parse("
+  o..
+  ...
+.......
+.......
+.......
+  ...
+  ..o
+");
+    assert_eq!(stranded.game_result(), Some(GameResult::Stranded(2)));
+
+    let movable = parse("
+  o..
+  ...
+.......
+.......
+.......
+  ..o
+  ..o
+");
+    assert_eq!(movable.game_result(), None);
+}
+
+#[test]
+fn test_all_valid_moves() {
+    let grid = Grid::new();
+    let moves = grid.all_valid_moves().list;
+
+    assert_eq!(moves.len(), 4);
+    assert_eq!(moves[0].origin, Position {row: 1, col: 3});
+    assert_eq!(moves[1].origin, Position {row: 3, col: 1});
+    assert_eq!(moves[2].origin, Position {row: 3, col: 5});
+    assert_eq!(moves[3].origin, Position {row: 5, col: 3});
+    assert!(moves.iter().all(|valid_move| valid_move.target == Position {row: 3, col: 3}));
+}
+
+#[test]
+fn test_token_count_and_movable_tokens() {
+    let mut grid = Grid::new();
+    assert_eq!(grid.token_count(), 32);
+    assert_eq!(grid.movable_tokens().len(), 4);
+
+    let valid_move = grid.all_valid_moves().list[0];
+    grid.apply_move(&valid_move).unwrap();
+    assert_eq!(grid.token_count(), 31);
+    assert_eq!(
+        grid.movable_tokens(),
+        vec![
+            Position {row: 2, col: 1},
+            Position {row: 2, col: 5},
+            Position {row: 4, col: 3},
+        ]
+    );
+}
+
+
+#[test]
+fn test_try_tile_from_position() {
+    let grid = Grid::new();
+
+    assert!(grid.try_tile_from_position(&Position {row: 6, col: 6}).is_ok());
+    assert_eq!(grid.try_tile_from_position(&Position {row: 7, col: 0}), Err(GridError::OffBoard {row: 7, col: 0}));
+}
+
+#[test]
+fn test_apply_move_validates() {
+    let mut grid = Grid::new();
+    let jump = |origin: (usize, usize), middle: (usize, usize), target: (usize, usize)| ValidMove {
+        origin: Position {row: origin.0, col: origin.1},
+        middle: Position {row: middle.0, col: middle.1},
+        target: Position {row: target.0, col: target.1},
+    };
+
+    let corner = jump((0, 0), (0, 1), (0, 2));
+    assert_eq!(grid.apply_move(&corner), Err(GridError::NotAHole(Position {row: 0, col: 0})));
+
+    let off_board = jump((3, 5), (3, 6), (3, 7));
+    assert_eq!(grid.apply_move(&off_board), Err(GridError::OffBoard {row: 3, col: 7}));
+
+    let not_a_jump = jump((3, 0), (3, 1), (3, 3));
+    assert_eq!(grid.apply_move(&not_a_jump), Err(GridError::IllegalJump {jump: not_a_jump, reason: JumpError::NotAJump}));
+
+    let occupied = jump((2, 2), (2, 3), (2, 4));
+    assert_eq!(grid.apply_move(&occupied), Err(GridError::IllegalJump {jump: occupied, reason: JumpError::TargetOccupied}));
+
+    let empty_origin = jump((3, 3), (3, 2), (3, 1));
+    assert_eq!(grid.apply_move(&empty_origin), Err(GridError::IllegalJump {jump: empty_origin, reason: JumpError::NoTokenAtOrigin}));
+
+    let valid = jump((3, 1), (3, 2), (3, 3));
+    assert_eq!(grid.apply_move(&valid), Ok(()));
+
+    let empty_middle = jump((3, 0), (3, 1), (3, 2));
+    assert_eq!(grid.apply_move(&empty_middle), Err(GridError::IllegalJump {jump: empty_middle, reason: JumpError::NoTokenToJumpOver}));
+    assert_eq!(grid.history, vec![valid]);
+}
+
+#[test]
+fn test_variants() {
+    let holes = |grid: &Grid| grid.tiles.iter().filter(|tile| !tile.is_corner).count();
+
+    for (variant, expected) in [
+        (BoardVariant::English, 33),
+        (BoardVariant::French, 37),
+        (BoardVariant::German, 45),
+        (BoardVariant::Wiegleb, 45),
+        (BoardVariant::Asymmetric, 39),
+        (BoardVariant::Diamond, 41),
+    ] {
+        let grid = Grid::from_variant(variant);
+
+        assert_eq!(holes(&grid), expected);
+        assert_eq!(grid.token_count(), expected - 1);
+        assert!(!grid.tile_from_position(&variant.vacancy()).has_token);
+        assert_eq!(grid.finish, variant.finish());
+        assert!(!Goal::for_grid(&grid).is_ruled_out_by_class(&grid), "{}", variant.name());
+        assert!(!grid.all_valid_moves().list.is_empty());
+        assert_eq!(BoardVariant::from_name(variant.name()), Some(variant));
+    }
+
+    assert_eq!(Grid::new().tiles, Grid::cross(7).unwrap().tiles);
+    assert_eq!(BoardVariant::from_name("French"), Some(BoardVariant::French));
+    assert_eq!(BoardVariant::from_name("hexagonal"), None);
+}
+
+#[test]
+fn test_variant_solutions() {
+    for (variant, solution) in [
+        (BoardVariant::French, "b2-d2 c4-c2 c6-c4 e6-c6 e4-e6 e7-e5 g4-e4 f6-f4 a3-c3 a5-c5 d3-b3 c1-c3 d1-d3 f2-d2 b6-d6 c4-c6 c7-c5 d5-f5 g5-e5 d7-d5 e4-e2 g3-e3 d3-f3 e1-e3 f3-f5 d5-b5 f5-d5 b3-d3 a4-c4 e3-c3-c5 d5-d3 b5-d5 d2-d4-d6"),
+        (BoardVariant::Diamond, "c3-e3 f3-d3 f5-f3 f7-f5 d7-f7 e9-e7 f8-f6 h4-f4 g6-g4 i5-g5 g4-g6 g7-g5 e1-e3 e4-g4 f2-f4 c4-e4 c6-c4 b4-d4 a5-c5 d5-d7 d8-d6 f5-h5 g3-g5 h5-f5 e6-g6 h6-f6 e4-c4 d2-d4 f5-f7-d7-d5 c4-c6 b6-d6 d5-d3-f3-f5-d5-d7 c7-e7"),
+    ] {
+        let mut grid = Grid::from_variant(variant);
+        for valid_move in parse_moves(solution).unwrap() {
+            grid.apply_move(&valid_move).unwrap();
+        }
+
+        assert_eq!(grid.game_result(), Some(GameResult::Perfect), "{}", variant.name());
+    }
+}
+
+#[test]
+fn test_asymmetric_and_french_layout() {
+    let asymmetric = Grid::from_variant(BoardVariant::Asymmetric);
+    assert!(asymmetric.is_corner(&Position {row: 2, col: 2}));
+    assert!(!asymmetric.is_corner(&Position {row: 0, col: 3}));
+    assert!(!asymmetric.is_corner(&Position {row: 7, col: 5}));
+    assert!(!asymmetric.is_corner(&Position {row: 3, col: 0}));
+    assert!(asymmetric.is_corner(&Position {row: 6, col: 6}));
+
+    let french = Grid::from_variant(BoardVariant::French);
+    assert!(!french.is_corner(&Position {row: 1, col: 1}));
+    assert!(french.is_corner(&Position {row: 0, col: 1}));
+    assert!(french.is_corner(&Position {row: 1, col: 0}));
+}
+
+#[test]
+fn test_parse_grid() {
+    let grid: Grid = "
+  ooo
+  ooo
+ooooooo
+ooo.ooo
+ooooooo
+  ooo
+  ooo
+".parse().unwrap();
+
+    assert_eq!(grid.tiles, Grid::new().tiles);
+    assert_eq!((grid.width, grid.height), (7, 7));
+
+    let narrow: Grid = "o.\n o".parse().unwrap();
+    assert_eq!((narrow.width, narrow.height), (2, 2));
+    assert!(narrow.tile_from_position(&Position {row: 0, col: 0}).has_token);
+    assert!(!narrow.tile_from_position(&Position {row: 0, col: 1}).has_token);
+    assert!(narrow.is_corner(&Position {row: 1, col: 0}));
+
+    assert_eq!("\n  \n".parse::<Grid>().err(), Some(GridError::EmptyBoard));
+    assert_eq!(
+        "ooo\no*o".parse::<Grid>().err(),
+        Some(GridError::InvalidCharacter {row: 1, col: 1, character: '*'})
+    );
+}
+
+#[test]
+fn test_display_grid() {
+    assert_eq!(Grid::new().to_string(), "  ooo\n  ooo\nooooooo\nooo.ooo\nooooooo\n  ooo\n  ooo");
+
+    for variant in BoardVariant::ALL {
+        let grid = Grid::from_variant(variant);
+        assert_eq!(grid.to_string().parse::<Grid>().unwrap().tiles, grid.tiles);
+    }
+}
+
+#[test]
+fn test_save_and_load() {
+    let mut grid = Grid::from_variant(BoardVariant::French);
+    let first = grid.all_valid_moves().list[0];
+    grid.apply_move(&first).unwrap();
+    let second = grid.all_valid_moves().list[0];
+    grid.apply_move(&second).unwrap();
+
+    let text = grid.to_save_string();
+    assert!(text.starts_with("board: french\nhistory: b2-d2 "));
+    assert!(!text.contains("hints"));
+
+    let mut loaded = Grid::from_save_string(&text).unwrap();
+    assert_eq!(loaded.variant, Some(BoardVariant::French));
+    assert_eq!(loaded.tiles, grid.tiles);
+    assert_eq!(loaded.history, vec![first, second]);
+    assert_eq!(loaded.finish, BoardVariant::French.finish());
+
+    loaded.undo();
+    loaded.undo();
+    assert_eq!(loaded.tiles, Grid::from_variant(BoardVariant::French).tiles);
+
+    grid.hints = vec![0, 2];
+    let text = grid.to_save_string();
+    assert!(text.contains("\nhints: 0 2\nfinish: d6\nposition:\n"));
+    assert_eq!(Grid::from_save_string(&text).unwrap().hints, vec![0, 2]);
+
+    grid.undo();
+    assert_eq!(grid.hints, vec![0, 1]);
+    assert_eq!(Grid::from_save_string(&grid.to_save_string()).unwrap().hints, vec![0, 1]);
+
+    // Hints still count after the move they were given for is undone and played again.
+    grid.redo();
+    assert_eq!(grid.hints, vec![0, 1]);
+    assert_eq!(Grid::from_save_string(&grid.to_save_string()).unwrap().hints, vec![0, 1]);
+}
+
+#[test]
+fn test_load_errors() {
+    let position = "position:\n  ooo\n  ooo\nooooooo\nooo.ooo\nooooooo\n  ooo\n  ooo\n";
+
+    assert!(matches!(
+        Grid::from_save_string(&format!("history:\n{}", position)),
+        Err(SaveError::MissingField("board"))
+    ));
+    assert!(matches!(
+        Grid::from_save_string(&format!("board: hexagonal\nhistory:\n{}", position)),
+        Err(SaveError::UnknownBoard(_))
+    ));
+    assert!(matches!(
+        Grid::from_save_string(&format!("board: english\nhistory: d4\n{}", position)),
+        Err(SaveError::Notation(_))
+    ));
+    assert!(matches!(
+        Grid::from_save_string(&format!("board: english\nhistory: d2-d4\n{}", position)),
+        Err(SaveError::InconsistentHistory(_))
+    ));
+
+    assert!(matches!(
+        Grid::from_save_string(&format!("board: english\nhistory:\nhints: x\n{}", position)),
+        Err(SaveError::InvalidHint(_))
+    ));
+    assert_eq!(
+        Grid::from_save_string(&format!("board: english\nhistory:\nhints: 1\n{}", position)).unwrap().hints,
+        vec![0]
+    );
+
+    let custom = Grid::from_save_string(&format!("board: custom\nhistory:\n{}", position)).unwrap();
+    assert_eq!(custom.variant, None);
+    assert_eq!(custom.tiles, Grid::new().tiles);
+}
+
+#[test]
+fn test_position_notation() {
+    let position = Position {row: 1, col: 3};
+
+    assert_eq!(position.to_notation(Notation::Algebraic), "d2");
+    assert_eq!(position.to_notation(Notation::Numeric), "42");
+    assert_eq!(Position::from_notation("d2"), Ok(position));
+    assert_eq!(Position::from_notation("42"), Ok(position));
+    assert_eq!(Position::from_notation("a1"), Ok(Position {row: 0, col: 0}));
+    assert_eq!(Position::from_notation("i9"), Ok(Position {row: 8, col: 8}));
+
+    for text in ["", "d", "d0", "4", "402", "04", "D2", "d2x"] {
+        assert_eq!(Position::from_notation(text), Err(NotationError::InvalidPosition(text.to_string())));
+    }
+}
+
+#[test]
+fn test_move_notation() {
+    let moves = parse_moves("d2-d4 f3-d3-d5 46-44").unwrap();
+
+    assert_eq!(moves.len(), 4);
+    assert_eq!(moves[0].middle, Position {row: 2, col: 3});
+    assert_eq!(moves[1].target, moves[2].origin);
+    assert_eq!(moves[3].to_notation(Notation::Algebraic), "d6-d4");
+    assert_eq!(format_moves(&moves, Notation::Algebraic), "d2-d4 f3-d3-d5 d6-d4");
+    assert_eq!(format_moves(&moves, Notation::Numeric), "42-44 63-43-45 46-44");
+    assert_eq!(parse_moves(&format_moves(&moves, Notation::Numeric)), Ok(moves));
+
+    assert_eq!(parse_moves(""), Ok(vec![]));
+    assert_eq!(parse_moves("d2-d5"), Err(NotationError::NotAJump("d2-d5".to_string())));
+    assert_eq!(parse_moves("d2"), Err(NotationError::NotAJump("d2".to_string())));
+    assert_eq!(parse_moves("d2-x"), Err(NotationError::InvalidPosition("x".to_string())));
+}
+
+#[test]
+fn test_replay_notation() {
+    let mut grid = Grid::new();
+
+    for valid_move in parse_moves("d2-d4 d5-d3 f4-d4-d2").unwrap() {
+        grid.apply_move(&valid_move).unwrap();
+    }
+
+    assert_eq!(grid.to_string(), "  ooo\n  ooo\nooo.ooo\nooo...o\nooo.ooo\n  ooo\n  ooo");
+    assert_eq!(format_moves(&grid.history, Notation::Algebraic), "d2-d4 d5-d3 f4-d4-d2");
+}
+
+#[test]
+fn test_bitboard_round_trip() {
+    for variant in BoardVariant::ALL {
+        let grid = Grid::from_variant(variant);
+        let layout = BitboardLayout::new(&grid).unwrap();
+        let board = layout.to_bitboard(&grid);
+
+        assert_eq!(board.token_count(), grid.token_count());
+        assert_eq!(layout.to_grid(board).variant, Some(variant));
+        assert_eq!(layout.to_grid(board).to_string(), grid.to_string());
+        assert_eq!(layout.to_grid(Bitboard(0)).token_count(), 0);
+    }
+
+    assert_eq!(BitboardLayout::new(&Grid::cross(13).unwrap()), Err(GridError::TooLarge {tiles: 169}));
+}
+
+#[test]
+fn test_bitboard_jumps() {
+    for variant in BoardVariant::ALL {
+        let mut grid = Grid::from_variant(variant);
+        let layout = BitboardLayout::new(&grid).unwrap();
+        let mut board = layout.to_bitboard(&grid);
+
+        // Always playing the last move spreads the game over the whole board.
+        loop {
+            let jumps = layout.jumps(board);
+            let valid_moves: Vec<ValidMove> =
+                jumps.iter().map(|jump| layout.to_valid_move(jump)).collect();
+            assert_eq!(valid_moves, grid.all_valid_moves().list);
+
+            let Some(jump) = jumps.last() else {
+                break;
+            };
+
+            board = layout.apply(board, jump);
+            grid.apply_move(&layout.to_valid_move(jump)).unwrap();
+            assert_eq!(layout.to_bitboard(&grid), board);
+        }
+    }
+
+    // A row wide enough to shift every bit out of the board when moving by a row.
+    for width in [64, 128] {
+        let grid: Grid = format!("oo.{}", "o".repeat(width - 3)).parse().unwrap();
+        let layout = BitboardLayout::new(&grid).unwrap();
+        let jumps = layout.jumps(layout.to_bitboard(&grid));
+
+        assert_eq!(jumps.iter().map(|jump| layout.to_valid_move(jump)).collect::<Vec<_>>(), grid.all_valid_moves().list);
+        assert_eq!(jumps.len(), 2);
+        assert_eq!(layout.unjumpable(), 1 | 1 << (width - 1));
+    }
+}
+
+#[test]
+fn test_merson_regions() {
+    for variant in BoardVariant::ALL {
+        let layout = BitboardLayout::new(&Grid::from_variant(variant)).unwrap();
+        let regions = layout.merson_regions();
+        assert!(regions.iter().all(|region| region & layout.holes == *region));
+
+        // With the region full, only its own tokens can jump into, out of or over it.
+        for region in regions.iter() {
+            for index in (0..BitboardLayout::MAX_TILES).filter(|index| layout.holes & !region & (1 << index) != 0) {
+                let board = Bitboard(layout.holes & !(1 << index));
+                for jump in layout.jumps(board).iter().filter(|jump| jump.mask() & region != 0) {
+                    assert_ne!(region & (1 << jump.origin), 0, "{:?} {:?}", variant, jump);
+                }
+            }
+        }
+    }
+
+    let layout = BitboardLayout::new(&Grid::new()).unwrap();
+    let regions = layout.merson_regions();
+    assert_eq!(regions.iter().filter(|region| region.count_ones() == 1).count(), 8);
+    assert_eq!(regions.len(), 8 + 12);
+}
+
+#[test]
+fn test_symmetry() {
+    let position = Position { row: 0, col: 3 };
+
+    assert_eq!(Symmetry::Rotate90.apply(&position, 7, 7), Some(Position { row: 3, col: 6 }));
+    assert_eq!(Symmetry::Rotate270.apply(&position, 7, 7), Some(Position { row: 3, col: 0 }));
+    assert_eq!(Symmetry::FlipDiagonal.apply(&position, 7, 7), Some(Position { row: 3, col: 0 }));
+    assert_eq!(Symmetry::FlipVertical.apply(&position, 7, 5), Some(Position { row: 4, col: 3 }));
+    assert_eq!(Symmetry::Rotate90.apply(&position, 7, 5), None);
+    assert_eq!(Symmetry::Identity.apply(&Position { row: 7, col: 0 }, 7, 7), None);
+
+    assert_eq!(Grid::new().symmetries(), Symmetry::ALL);
+    assert_eq!(Grid::from_variant(BoardVariant::French).symmetries(), Symmetry::ALL);
+    assert_eq!(
+        Grid::from_variant(BoardVariant::Asymmetric).symmetries(),
+        [Symmetry::Identity, Symmetry::FlipDiagonal]
+    );
+    assert_eq!(Grid::from_mask(3, 1, &[true; 3]).symmetries().len(), 4);
+}
+
+#[test]
+fn test_canonical() {
+    let mut grid = Grid::new();
+    grid.apply_move(&parse_moves("d2-d4").unwrap()[0]).unwrap();
+
+    let rotated = grid.transformed(Symmetry::Rotate90);
+    assert_eq!(rotated.to_string(), "  ooo\n  ooo\nooooooo\noooo..o\nooooooo\n  ooo\n  ooo");
+    assert_eq!(format_moves(&rotated.history, Notation::Algebraic), "f4-d4");
+
+    let canonical = grid.canonical().to_string();
+    for moves in ["d2-d4", "f4-d4", "d6-d4", "b4-d4"] {
+        let mut other = Grid::new();
+        other.apply_move(&parse_moves(moves).unwrap()[0]).unwrap();
+
+        assert_eq!(other.canonical().to_string(), canonical);
+        assert!(other.is_symmetric_to(&grid));
+    }
+    assert!(!grid.is_symmetric_to(&Grid::new()));
+
+    let layout = BitboardLayout::new(&grid).unwrap();
+    let board = layout.to_bitboard(&grid);
+    assert_eq!(layout.symmetries(), Symmetry::ALL);
+    assert_eq!(layout.transform(board, Symmetry::Rotate90), layout.to_bitboard(&rotated));
+    assert_eq!(
+        layout.canonical(board, &Symmetry::ALL),
+        layout.to_bitboard(&grid.canonical())
+    );
+}
+
+#[test]
+fn test_zobrist_hash() {
+    let mut grid = Grid::new();
+    let start = grid.zobrist_hash();
+    assert_eq!(start, grid.compute_zobrist_hash());
+    assert_ne!(start, Grid::from_mask(7, 7, &[true; 49]).zobrist_hash());
+
+    grid.apply_move(&parse_moves("d2-d4").unwrap()[0]).unwrap();
+    assert_ne!(grid.zobrist_hash(), start);
+    assert_eq!(grid.zobrist_hash(), grid.compute_zobrist_hash());
+
+    grid.undo();
+    assert_eq!(grid.zobrist_hash(), start);
+    grid.redo();
+    assert_eq!(grid.zobrist_hash(), grid.compute_zobrist_hash());
+
+    // Reaching the same position in a different order gives the same hash.
+    let mut first = Grid::new();
+    let mut second = Grid::new();
+    for valid_move in parse_moves("d2-d4 f3-d3 f5-f3 e5-e3").unwrap() {
+        first.apply_move(&valid_move).unwrap();
+    }
+    for valid_move in parse_moves("d2-d4 f3-d3 e5-e3 f5-f3").unwrap() {
+        second.apply_move(&valid_move).unwrap();
+    }
+    assert_eq!(first.to_string(), second.to_string());
+    assert_eq!(first.zobrist_hash(), second.zobrist_hash());
+
+    let parsed: Grid = first.to_string().parse().unwrap();
+    assert_eq!(parsed.zobrist_hash(), first.zobrist_hash());
+}
+
+#[test]
+fn test_pagoda() {
+    for variant in [BoardVariant::English, BoardVariant::French] {
+        let grid = Grid::from_variant(variant);
+        let pagoda = Pagoda::classic(variant).unwrap();
+
+        assert!(pagoda.is_valid());
+        assert!(pagoda.fits(&grid));
+        assert_eq!(Pagoda::for_grid(&grid), Some(pagoda.clone()));
+        assert!(!pagoda.fits(&Grid::from_variant(BoardVariant::German)));
+        assert_eq!(pagoda.weight(&grid.center()), Some(2));
+    }
+    assert_eq!(Pagoda::classic(BoardVariant::Diamond), None);
+
+    let mut grid = Grid::new();
+    let pagoda = Pagoda::classic(BoardVariant::English).unwrap();
+    assert_eq!(pagoda.value(&grid), 12);
+    assert_eq!(pagoda.to_string().lines().nth(3), Some("  1  1  1  2  1  1  1"));
+
+    // No sequence of jumps can increase the value.
+    let mut value = pagoda.value(&grid);
+    while let Some(valid_move) = grid.all_valid_moves().list.first().copied() {
+        grid.apply_move(&valid_move).unwrap();
+
+        let layout = BitboardLayout::new(&grid).unwrap();
+        assert!(pagoda.value(&grid) <= value);
+        assert_eq!(pagoda.bitboard_value(layout.to_bitboard(&grid)), pagoda.value(&grid));
+        value = pagoda.value(&grid);
+    }
+
+    let mut weights = pagoda.weights.clone();
+    weights[3 * 7 + 3] = Some(3);
+    assert!(!Pagoda::new(7, 7, weights).is_valid());
+}
+
+#[test]
+fn test_position_class() {
+    let grid = Grid::new();
+    let center = grid.center();
+
+    assert_eq!(PositionClass::of([]).index(), 0);
+    assert_eq!(grid.position_class(), PositionClass::of([&center]));
+    assert_ne!(grid.position_class(), PositionClass::of([&Position { row: 3, col: 2 }]));
+    assert_eq!(PositionClass::of([&Position { row: 0, col: 3 }]), PositionClass::of([&center]));
+
+    // The sixteen classes all occur among the positions of one or two tokens.
+    let mut classes: Vec<u8> = Vec::new();
+    for first in grid.tiles.iter() {
+        for second in grid.tiles.iter() {
+            let class = PositionClass::of([&first.position, &second.position]).index();
+            if !classes.contains(&class) {
+                classes.push(class);
+            }
+        }
+    }
+    assert_eq!(classes.len(), 16);
+
+    let mut played = grid.clone();
+    while let Some(valid_move) = played.all_valid_moves().list.first().copied() {
+        played.apply_move(&valid_move).unwrap();
+        assert_eq!(played.position_class(), grid.position_class());
+    }
+
+    assert!(grid.could_reach(&played));
+    assert!(!played.could_reach(&grid));
+
+    let target: Grid = "
+  ...
+  ...
+...o...
+.......
+.......
+  ...
+  ...
+".parse().unwrap();
+    assert!(!grid.could_reach(&target));
+}
+
+#[test]
+fn test_multi_jump() {
+    let jumps = parse_moves("d2-d4 f3-d3 d4-d2 c5-c3").unwrap();
+    let mut multi_jump = MultiJump::new(jumps[0]);
+
+    assert_eq!(multi_jump.origin(), Position { row: 1, col: 3 });
+    assert!(!multi_jump.extend(jumps[1]));
+    assert!(multi_jump.continues_with(&jumps[2]));
+    assert!(multi_jump.extend(jumps[2]));
+    assert_eq!(multi_jump.target(), Position { row: 1, col: 3 });
+    assert_eq!(multi_jump.to_notation(Notation::Algebraic), "d2-d4-d2");
+
+    let moves = MultiJump::group(&parse_moves("d2-d4 f3-d3-d5 d6-d4").unwrap());
+    assert_eq!(moves.len(), 3);
+    assert_eq!(moves[1].jumps.len(), 2);
+    assert_eq!(moves[1].to_notation(Notation::Numeric), "63-43-45");
+    assert_eq!(MultiJump::group(&[]), vec![]);
+
+    let mut grid = Grid::new();
+    for valid_move in parse_moves("d2-d4 d5-d3 f4-d4-d2").unwrap() {
+        grid.apply_move(&valid_move).unwrap();
+    }
+    assert_eq!(grid.history.len(), 4);
+    assert_eq!(grid.moves().len(), 3);
+    assert_eq!(grid.moves()[2].to_notation(Notation::Algebraic), "f4-d4-d2");
+}
+
+#[test]
+fn test_with_vacancy() {
+    let mut grid = Grid::from_variant(BoardVariant::French);
+    grid.apply_move(&grid.all_valid_moves().list[0]).unwrap();
+
+    let vacancy = Position { row: 0, col: 2 };
+    let fresh = grid.with_vacancy(&vacancy).unwrap();
+    assert_eq!(fresh.variant, Some(BoardVariant::French));
+    assert_eq!(fresh.token_count(), 36);
+    assert!(!fresh.tile_from_position(&vacancy).has_token);
+    assert!(fresh.history.is_empty());
+    assert_eq!(fresh.zobrist_hash(), fresh.compute_zobrist_hash());
+
+    assert_eq!(grid.with_vacancy(&Position { row: 0, col: 0 }).err(), Some(GridError::NotAHole(Position { row: 0, col: 0 })));
+    assert_eq!(grid.with_vacancy(&Position { row: 9, col: 0 }).err(), Some(GridError::OffBoard { row: 9, col: 0 }));
+    assert_eq!(Grid::new().with_vacancy(&Grid::new().center()).unwrap().tiles, Grid::new().tiles);
+}
+
+#[test]
+fn test_with_finish() {
+    let finish = Position {row: 0, col: 3};
+    let grid = Grid::new().with_finish(&finish).unwrap();
+    assert_eq!(Grid::new().finish, Grid::new().center());
+    assert_eq!(grid.finish, finish);
+    assert_eq!(grid.with_finish(&Position {row: 0, col: 0}).err(), Some(GridError::NotAHole(Position {row: 0, col: 0})));
+
+    // Only the finish hole counts as a perfect game.
+    let mut single = Grid::from_mask(3, 1, &[true, true, true]);
+    single.update_tile(&Position {row: 0, col: 2}, false, false);
+    single.apply_move(&single.all_valid_moves().list[0]).unwrap();
+    assert_eq!(single.game_result(), Some(GameResult::SinglePeg(Position {row: 0, col: 2})));
+    assert_eq!(single.with_finish(&Position {row: 0, col: 2}).unwrap().game_result(), Some(GameResult::Perfect));
+
+    let loaded = Grid::from_save_string(&grid.to_save_string()).unwrap();
+    assert!(grid.to_save_string().contains("finish: d1\n"));
+    assert_eq!(loaded.finish, finish);
+    assert!(!Grid::new().to_save_string().contains("finish"));
+    assert_eq!(Grid::from_save_string(&grid.to_save_string().replace("d1", "a1")).err().map(|error| error.to_string()), Some("invalid finish hole \"a1\"".to_string()));
+
+    assert_eq!(grid.transformed(Symmetry::FlipVertical).finish, Position {row: 6, col: 3});
+}
+
+#[test]
+fn test_puzzle() {
+    let puzzle = Puzzle::from_name("plus").unwrap();
+    assert_eq!(puzzle.name, "Plus");
+    assert_eq!(puzzle.token_count(), 9);
+    assert!(Puzzle::from_name("nonesuch").is_none());
+
+    let mut grid = puzzle.grid();
+    assert_eq!(grid.variant, Some(BoardVariant::English));
+    assert_eq!(grid.token_count(), 9);
+    assert_eq!(grid.game_result(), None);
+    assert_eq!(Goal::for_grid(&grid).to_string(), "the goal pattern");
+
+    // A single token outside of the pattern is no win.
+    for valid_move in parse_moves("d3-d1 d5-d3 b4-d4-d2 d1-d3 f4-d4 d3-d5").unwrap() {
+        grid.apply_move(&valid_move).unwrap();
+    }
+    assert_eq!(grid.game_result(), None);
+    grid.apply_move(&parse_moves("d6-d4").unwrap()[0]).unwrap();
+    assert_eq!(grid.game_result(), Some(GameResult::Perfect));
+
+    // Reaching the pattern wins even with moves left.
+    let mut two = puzzle.grid();
+    two.pattern = Some(two.tiles.iter().map(|tile| tile.has_token).collect());
+    assert_eq!(two.game_result(), Some(GameResult::Perfect));
+    assert!(two.with_vacancy(&two.center()).unwrap().pattern.is_none());
+
+    let saved = puzzle.grid().to_save_string();
+    assert!(saved.contains("goal: d4\n"));
+    assert_eq!(Grid::from_save_string(&saved).unwrap().pattern, Some(puzzle.goal.clone()));
+    assert!(!Grid::new().to_save_string().contains("goal"));
+    assert_eq!(Grid::from_save_string(&saved.replace("goal: d4", "goal: a1")).err().map(|error| error.to_string()), Some("invalid goal hole \"a1\"".to_string()));
+
+    let mut top = Grid::new();
+    let mut pattern = vec![false; 49];
+    pattern[top.to_index(&Position {row: 0, col: 3})] = true;
+    top.pattern = Some(pattern);
+    let bottom = top.transformed(Symmetry::FlipVertical).pattern.unwrap();
+    assert_eq!(bottom.iter().position(|has_token| *has_token), Some(top.to_index(&Position {row: 6, col: 3})));
+
+    assert_eq!(Puzzle::new("Empty", BoardVariant::English, vec![false; 48], vec![false; 49]), Err(PuzzleError::WrongSize {expected: 49, found: 48}));
+    assert_eq!(Puzzle::new("Corner", BoardVariant::English, vec![true; 49], vec![false; 49]), Err(PuzzleError::NotAHole(Position {row: 0, col: 0})));
+    assert_eq!(Puzzle::from_drawings("Small", BoardVariant::English, "ooo", "o.."), Err(PuzzleError::WrongSize {expected: 49, found: 3}));
+    assert_eq!(Puzzle::from_drawings("Typo", BoardVariant::English, "x", "o"), Err(PuzzleError::Grid(GridError::InvalidCharacter {row: 0, col: 0, character: 'x'})));
+
+    let square = Grid::from_mask(7, 7, &[true; 49]).to_string();
+    assert_eq!(Puzzle::from_drawings("Square", BoardVariant::English, &square, &square), Err(PuzzleError::WrongBoard(Position {row: 0, col: 0})));
+}
+
+#[test]
+fn test_puzzle_pack() {
+    let classics = Puzzle::classics();
+    assert_eq!(classics.len(), 5);
+    assert_eq!(classics[0].par, Some(5));
+    assert_eq!(classics[0].solution.as_ref().map(|solution| solution.len()), Some(5));
+    assert_eq!(Puzzle::parse_pack(&Puzzle::format_pack(&classics)).unwrap(), classics);
+
+    let cross = classics[0].to_pack_string();
+    assert!(cross.starts_with("name: Cross\nboard: english\npar: 5\nsolution: d3-b3 d5-d3 e3-c3 b3-d3 d2-d4\nstart:\n  ...\n  .o.\n..ooo..\n"));
+
+    let mut plain = classics[1].clone();
+    plain.par = None;
+    plain.solution = None;
+    let text = format!("# Comment\n\n{}\n{}", plain.to_pack_string(), cross);
+    assert_eq!(Puzzle::parse_pack(&text).unwrap(), vec![plain, classics[0].clone()]);
+    assert_eq!(Puzzle::parse_pack("").unwrap(), vec![]);
+
+    let error = |text: &str| Puzzle::parse_pack(text).err().map(|error| error.to_string());
+    assert_eq!(error("board: english"), Some("missing field \"name\"".to_string()));
+    assert_eq!(error(&cross.replace("board: english\n", "")), Some("puzzle \"Cross\": missing field \"board\"".to_string()));
+    assert_eq!(error(&cross.replace("english", "dutch")), Some("puzzle \"Cross\": unknown board \"dutch\"".to_string()));
+    assert_eq!(error(&cross.replace("english", "french")), Some("puzzle \"Cross\": the drawing does not match the board at row 1, col 1".to_string()));
+    assert_eq!(error(&cross.replace("par: 5", "par: five")), Some("puzzle \"Cross\": invalid par \"five\"".to_string()));
+    assert_eq!(error(&cross.replace(" d2-d4\n", "\n")), Some("puzzle \"Cross\": the solution does not reach the goal".to_string()));
+    assert_eq!(error(&cross.replace("d3-b3", "d3-b2")), Some("puzzle \"Cross\": invalid solution: \"d3-b2\" is not a jump".to_string()));
+    assert!(matches!(Puzzle::parse_pack(&cross.replace("d3-b3 d5-d3", "d5-d3 d3-b3")), Err(PuzzleError::InPuzzle {error, ..}) if matches!(*error, PuzzleError::IllegalSolution(_))));
+    assert_eq!(error(&cross[..cross.find("goal:").unwrap()]), Some("puzzle \"Cross\": missing field \"goal\"".to_string()));
+}
@@ -0,0 +1,467 @@
+pub mod bitboard;
+pub mod error;
+pub mod game_move;
+pub mod game_result;
+pub mod notation;
+pub mod pagoda;
+pub mod position;
+pub mod position_class;
+pub mod puzzle;
+pub mod puzzle_pack;
+pub mod save;
+pub mod symmetry;
+pub mod text;
+pub mod tile;
+pub mod variant;
+pub mod zobrist;
+
+#[cfg(test)]
+#[path = "./grid_test.rs"]
+mod grid_test;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct Grid {
+    // The classic board this grid was created from, if any.
+    pub variant: Option<BoardVariant>,
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<Tile>,
+    pub history: Vec<ValidMove>,
+    pub undone: Vec<ValidMove>,
+    // Length of the history each time the player asked for a hint.
+    pub hints: Vec<usize>,
+    // Hole the last token has to end up in for a perfect game, the center or the variant's finish
+    // unless chosen otherwise.
+    pub finish: Position,
+    // Tokens a puzzle has to end up with, indexed like `tiles`. Takes the place of `finish`.
+    pub pattern: Option<Vec<bool>>,
+    // Zobrist hash of the tokens, see `zobrist_hash`.
+    zobrist: u64,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Grid {
+    /**
+     * The English 33-hole board with the center hole left empty.
+     */
+    pub fn new() -> Self {
+        Grid::from_variant(BoardVariant::English)
+    }
+
+    /**
+     * One of the classic boards, with every hole but the variant's standard vacancy filled and the
+     * variant's standard finish hole.
+     */
+    pub fn from_variant(variant: BoardVariant) -> Self {
+        let (width, height) = variant.size();
+        let holes: Vec<bool> = (0..(width * height))
+            .map(|index| {
+                variant.is_hole(&Position {
+                    row: index / width,
+                    col: index % width,
+                })
+            })
+            .collect();
+
+        let mut grid = Grid::from_mask(width, height, &holes);
+        grid.variant = Some(variant);
+        grid.update_tile(&variant.vacancy(), false, false);
+        grid.finish = variant.finish();
+
+        grid
+    }
+
+    /**
+     * A new game on the same board, with every hole but the given vacancy filled. It is won with a
+     * single token in the finish hole, even if the grid was a puzzle.
+     */
+    pub fn with_vacancy(&self, vacancy: &Position) -> Result<Self, GridError> {
+        let tile = self.try_tile_from_position(vacancy)?;
+        if tile.is_corner {
+            return Err(GridError::NotAHole(*vacancy));
+        }
+
+        let mut grid = self.clone();
+        grid.history.clear();
+        grid.undone.clear();
+        grid.hints.clear();
+        grid.pattern = None;
+        for tile in self.tiles.iter() {
+            let has_token = !tile.is_corner && tile.position != *vacancy;
+            grid.update_tile(&tile.position, tile.is_corner, has_token);
+        }
+
+        Ok(grid)
+    }
+
+    /**
+     * The same game with the last token to be left in the given hole instead.
+     */
+    pub fn with_finish(&self, finish: &Position) -> Result<Self, GridError> {
+        let tile = self.try_tile_from_position(finish)?;
+        if tile.is_corner {
+            return Err(GridError::NotAHole(*finish));
+        }
+
+        let mut grid = self.clone();
+        grid.finish = *finish;
+
+        Ok(grid)
+    }
+
+    /**
+     * A square cross-shaped board with arms three holes wide, e.g. 5 for 21 holes or 9 for 45 holes.
+     * Every hole but the center one holds a token. Fails unless the size is odd and at least 3, as
+     * the arms would not be centered otherwise.
+     */
+    pub fn cross(size: usize) -> Result<Self, GridError> {
+        if size < 3 || size.is_multiple_of(2) {
+            return Err(GridError::CrossSize { size });
+        }
+
+        let arm = ((size - 3) / 2)..((size - 3) / 2 + 3);
+        let holes: Vec<bool> = (0..(size * size))
+            .map(|index| arm.contains(&(index / size)) || arm.contains(&(index % size)))
+            .collect();
+
+        let mut grid = Grid::from_mask(size, size, &holes);
+        let center = grid.center();
+        grid.update_tile(&center, false, false);
+
+        Ok(grid)
+    }
+
+    /**
+     * Creates a `width` x `height` board with a token in every hole. The mask lists whether each
+     * position is a hole, row by row.
+     */
+    pub fn from_mask(width: usize, height: usize, holes: &[bool]) -> Self {
+        assert_eq!(
+            holes.len(),
+            width * height,
+            "the hole mask does not match the board size"
+        );
+
+        let tiles = holes
+            .iter()
+            .enumerate()
+            .map(|(index, is_hole)| Tile {
+                position: Position {
+                    row: index / width,
+                    col: index % width,
+                },
+                is_corner: !is_hole,
+                has_token: *is_hole,
+            })
+            .collect();
+
+        let mut grid = Self {
+            variant: None,
+            width,
+            height,
+            tiles,
+            history: Vec::new(),
+            undone: Vec::new(),
+            hints: Vec::new(),
+            finish: Position {
+                row: height / 2,
+                col: width / 2,
+            },
+            pattern: None,
+            zobrist: 0,
+        };
+        grid.zobrist = grid.compute_zobrist_hash();
+
+        grid
+    }
+
+    pub fn center(&self) -> Position {
+        Position {
+            row: self.height / 2,
+            col: self.width / 2,
+        }
+    }
+
+    pub fn tile_from_position(&self, position: &Position) -> &Tile {
+        &self.tiles[self.to_index(position)]
+    }
+
+    pub fn try_tile_from_position(&self, position: &Position) -> Result<&Tile, GridError> {
+        if position.row >= self.height || position.col >= self.width {
+            return Err(GridError::OffBoard {
+                row: position.row as i64,
+                col: position.col as i64,
+            });
+        }
+
+        Ok(self.tile_from_position(position))
+    }
+
+    pub fn calculate_valid_moves(&self, origin: &Position) -> ValidMoves {
+        let options = [
+            self.calculate_valid_move(origin, MoveDirection::Left),
+            self.calculate_valid_move(origin, MoveDirection::Right),
+            self.calculate_valid_move(origin, MoveDirection::Up),
+            self.calculate_valid_move(origin, MoveDirection::Down),
+        ];
+
+        ValidMoves {
+            list: options.iter().flatten().copied().collect(),
+        }
+    }
+
+    pub fn calculate_valid_move(
+        &self,
+        origin: &Position,
+        direction: MoveDirection,
+    ) -> Option<ValidMove> {
+        let mut middle = *origin;
+        let mut target = *origin;
+
+        match direction {
+            MoveDirection::Left => {
+                if origin.col < 2 {
+                    return None;
+                }
+
+                middle.col -= 1;
+                target.col -= 2;
+            }
+            MoveDirection::Right => {
+                if origin.col + 2 >= self.width {
+                    return None;
+                }
+
+                middle.col += 1;
+                target.col += 2;
+            }
+            MoveDirection::Up => {
+                if origin.row < 2 {
+                    return None;
+                }
+
+                middle.row -= 1;
+                target.row -= 2;
+            }
+            MoveDirection::Down => {
+                if origin.row + 2 >= self.height {
+                    return None;
+                }
+
+                middle.row += 1;
+                target.row += 2;
+            }
+        }
+
+        if !self.is_valid_move(origin, &middle, &target) {
+            return None;
+        }
+
+        Some(ValidMove {
+            origin: *origin,
+            middle,
+            target,
+        })
+    }
+
+    /**
+     * Lists every valid move on the board, ordered by the origin's index and then by direction
+     * (left, right, up, down).
+     */
+    pub fn all_valid_moves(&self) -> ValidMoves {
+        let mut moves = Vec::new();
+
+        for tile in self.tiles.iter() {
+            if !tile.has_token {
+                continue;
+            }
+
+            moves.extend(self.calculate_valid_moves(&tile.position).list);
+        }
+
+        ValidMoves { list: moves }
+    }
+
+    pub fn token_count(&self) -> usize {
+        self.tiles.iter().filter(|tile| tile.has_token).count()
+    }
+
+    /**
+     * Lists the positions of all tokens that have at least one valid move, in index order.
+     */
+    pub fn movable_tokens(&self) -> Vec<Position> {
+        let mut positions: Vec<Position> = self
+            .all_valid_moves()
+            .list
+            .iter()
+            .map(|valid_move| valid_move.origin)
+            .collect();
+        positions.dedup();
+
+        positions
+    }
+
+    /**
+     * Checks the whole board for remaining moves. Returns `None` as long as any token can still jump
+     * and the goal is not reached yet.
+     */
+    pub fn game_result(&self) -> Option<GameResult> {
+        if Goal::for_grid(self).is_reached(self) {
+            return Some(GameResult::Perfect);
+        }
+
+        if !self.all_valid_moves().list.is_empty() {
+            return None;
+        }
+
+        let tokens: Vec<&Tile> = self.tiles.iter().filter(|tile| tile.has_token).collect();
+
+        match tokens.as_slice() {
+            [tile] => Some(GameResult::SinglePeg(tile.position)),
+            _ => Some(GameResult::Stranded(tokens.len())),
+        }
+    }
+
+    pub fn is_valid_move(&self, origin: &Position, middle: &Position, target: &Position) -> bool {
+        let origin_tile = self.tile_from_position(origin);
+        let middle_tile = self.tile_from_position(middle);
+        let target_tile = self.tile_from_position(target);
+
+        origin_tile.has_token
+            && !origin_tile.is_corner
+            && middle_tile.has_token
+            && !middle_tile.is_corner
+            && !target_tile.has_token
+            && !target_tile.is_corner
+    }
+
+    pub fn update_tile(&mut self, position: &Position, is_corner: bool, has_token: bool) {
+        let index = self.to_index(position);
+
+        if self.tiles[index].has_token != has_token {
+            self.zobrist ^= zobrist_key(index);
+        }
+
+        self.tiles[index].is_corner = is_corner;
+        self.tiles[index].has_token = has_token;
+    }
+
+    /**
+     * Checks that the move is a legal jump on this board, reporting why it is not otherwise.
+     */
+    pub fn validate_move(&self, valid_move: &ValidMove) -> Result<(), GridError> {
+        let origin = self.try_tile_from_position(&valid_move.origin)?;
+        let middle = self.try_tile_from_position(&valid_move.middle)?;
+        let target = self.try_tile_from_position(&valid_move.target)?;
+
+        for tile in [origin, middle, target] {
+            if tile.is_corner {
+                return Err(GridError::NotAHole(tile.position));
+            }
+        }
+
+        let is_jump =
+            ValidMove::between(&valid_move.origin, &valid_move.target) == Some(*valid_move);
+        let reason = if !is_jump {
+            JumpError::NotAJump
+        } else if !origin.has_token {
+            JumpError::NoTokenAtOrigin
+        } else if !middle.has_token {
+            JumpError::NoTokenToJumpOver
+        } else if target.has_token {
+            JumpError::TargetOccupied
+        } else {
+            return Ok(());
+        };
+
+        Err(GridError::IllegalJump {
+            jump: *valid_move,
+            reason,
+        })
+    }
+
+    /**
+     * Validates and applies the move, then records it in the history. Any previously undone moves
+     * are discarded.
+     */
+    pub fn apply_move(&mut self, valid_move: &ValidMove) -> Result<(), GridError> {
+        self.validate_move(valid_move)?;
+
+        self.jump(valid_move);
+        self.history.push(*valid_move);
+        self.undone.clear();
+
+        Ok(())
+    }
+
+    /**
+     * Reverts the last applied move and returns it, so callers can restore the jumped token. Hints
+     * given after the move are kept, as if they had been asked for before it.
+     */
+    pub fn undo(&mut self) -> Option<ValidMove> {
+        let valid_move = self.history.pop()?;
+        let moves_played = self.history.len();
+        for hint in self.hints.iter_mut() {
+            *hint = (*hint).min(moves_played);
+        }
+
+        self.update_tile(&valid_move.origin, false, true);
+        self.update_tile(&valid_move.middle, false, true);
+        self.update_tile(&valid_move.target, false, false);
+        self.undone.push(valid_move);
+
+        Some(valid_move)
+    }
+
+    /**
+     * Re-applies the last undone move and returns it.
+     */
+    pub fn redo(&mut self) -> Option<ValidMove> {
+        let valid_move = self.undone.pop()?;
+
+        self.jump(&valid_move);
+        self.history.push(valid_move);
+
+        Some(valid_move)
+    }
+
+    /**
+     * The history grouped into moves, where a token jumping several times in a row makes one move.
+     */
+    pub fn moves(&self) -> Vec<MultiJump> {
+        MultiJump::group(&self.history)
+    }
+
+    fn jump(&mut self, valid_move: &ValidMove) {
+        self.update_tile(&valid_move.origin, false, false);
+        self.update_tile(&valid_move.middle, false, false);
+        self.update_tile(&valid_move.target, false, true);
+    }
+
+    pub fn to_index(&self, position: &Position) -> usize {
+        position.row * self.width + position.col
+    }
+
+    pub fn from_index(&self, index: usize) -> Position {
+        Position {
+            row: index / self.width,
+            col: index % self.width,
+        }
+    }
+
+    /**
+     * Whether the position lies outside of the board's holes. Positions off the grid count as
+     * corners as well.
+     */
+    pub fn is_corner(&self, position: &Position) -> bool {
+        self.try_tile_from_position(position)
+            .map_or(true, |tile| tile.is_corner)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::prelude::*;
+
+/**
+ * The two ways holes are named in the peg solitaire literature:
+ *
+ * - `Algebraic`: a column letter from `a` on the left and a row number from `1` at the top, so
+ *   the center of the English board is `d4`.
+ * - `Numeric`: the column digit followed by the row digit, both counted from `1`, so the center
+ *   of the English board is `44`.
+ *
+ * A move is written as its origin and target joined by `-`, e.g. `d2-d4`. Consecutive jumps by
+ * the same token are chained, e.g. `d2-d4-f4`, and separate moves are separated by spaces.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    Algebraic,
+    Numeric,
+}
+
+impl Position {
+    pub fn to_notation(&self, notation: Notation) -> String {
+        match notation {
+            Notation::Algebraic => format!("{}{}", (b'a' + self.col as u8) as char, self.row + 1),
+            Notation::Numeric => format!("{}{}", self.col + 1, self.row + 1),
+        }
+    }
+
+    /**
+     * Reads a hole in either notation, telling them apart by the first character.
+     */
+    pub fn from_notation(text: &str) -> Result<Self, NotationError> {
+        let invalid = || NotationError::InvalidPosition(text.to_string());
+        let mut chars = text.chars();
+
+        let (col, row) = match chars.next() {
+            Some(letter @ 'a'..='z') => {
+                let row: usize = chars.as_str().parse().map_err(|_| invalid())?;
+                (letter as usize - 'a' as usize + 1, row)
+            }
+            Some(digit @ '1'..='9') => {
+                let row = chars.next().and_then(|row| row.to_digit(10));
+                match (row, chars.next()) {
+                    (Some(row), None) => (digit as usize - '0' as usize, row as usize),
+                    _ => return Err(invalid()),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        if col == 0 || row == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Position {
+            row: row - 1,
+            col: col - 1,
+        })
+    }
+}
+
+impl ValidMove {
+    pub fn to_notation(&self, notation: Notation) -> String {
+        format!(
+            "{}-{}",
+            self.origin.to_notation(notation),
+            self.target.to_notation(notation)
+        )
+    }
+}
+
+impl MultiJump {
+    /**
+     * The origin followed by every hole the token lands in, e.g. `d2-d4-f4`.
+     */
+    pub fn to_notation(&self, notation: Notation) -> String {
+        let mut text = self.origin().to_notation(notation);
+        for jump in self.jumps.iter() {
+            text.push('-');
+            text.push_str(&jump.target.to_notation(notation));
+        }
+
+        text
+    }
+}
+
+/**
+ * Writes the moves out, chaining consecutive jumps of the same token into a single entry as in
+ * `MultiJump`.
+ */
+pub fn format_moves(moves: &[ValidMove], notation: Notation) -> String {
+    MultiJump::group(moves)
+        .iter()
+        .map(|multi_jump| multi_jump.to_notation(notation))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/**
+ * Reads moves written by `format_moves`, splitting chained jumps back into single moves. Only the
+ * shape of each jump is checked, not whether it is legal on a particular board.
+ */
+pub fn parse_moves(text: &str) -> Result<Vec<ValidMove>, NotationError> {
+    let mut moves = Vec::new();
+
+    for entry in text.split_whitespace() {
+        let positions = entry
+            .split('-')
+            .map(Position::from_notation)
+            .collect::<Result<Vec<Position>, NotationError>>()?;
+
+        if positions.len() < 2 {
+            return Err(NotationError::NotAJump(entry.to_string()));
+        }
+
+        for pair in positions.windows(2) {
+            let jump = ValidMove::between(&pair[0], &pair[1])
+                .ok_or_else(|| NotationError::NotAJump(entry.to_string()))?;
+            moves.push(jump);
+        }
+    }
+
+    Ok(moves)
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+/**
+ * The classic pagoda of the English board, centered on the middle hole. It also holds on the
+ * French board, where the four extra holes weigh 0. Entries outside the board are ignored.
+ */
+const CLASSIC: [[i32; 7]; 7] = [
+    [0, 0, -1, 1, -1, 0, 0],
+    [0, 0, 1, 1, 1, 0, 0],
+    [-1, 1, 0, 1, 0, 1, -1],
+    [1, 1, 1, 2, 1, 1, 1],
+    [-1, 1, 0, 1, 0, 1, -1],
+    [0, 0, 1, 1, 1, 0, 0],
+    [0, 0, -1, 1, -1, 0, 0],
+];
+
+/**
+ * A weighting of the holes such that no jump increases the total weight of the tokens: the target
+ * never weighs more than the origin and the jumped-over hole together. A position whose value is
+ * below the value of a goal position can therefore never reach it.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pagoda {
+    pub width: usize,
+    pub height: usize,
+    // Weights indexed like `Grid::tiles`, with `None` for positions that are not holes.
+    pub weights: Vec<Option<i32>>,
+}
+
+impl Pagoda {
+    pub fn new(width: usize, height: usize, weights: Vec<Option<i32>>) -> Self {
+        assert_eq!(
+            weights.len(),
+            width * height,
+            "the weights do not match the board size"
+        );
+
+        Self {
+            width,
+            height,
+            weights,
+        }
+    }
+
+    /**
+     * The classic pagoda for a board variant, if there is one.
+     */
+    pub fn classic(variant: BoardVariant) -> Option<Self> {
+        match variant {
+            BoardVariant::English | BoardVariant::French => {
+                let (width, height) = variant.size();
+                let weights = (0..(width * height))
+                    .map(|index| Position {
+                        row: index / width,
+                        col: index % width,
+                    })
+                    .map(|position| {
+                        variant
+                            .is_hole(&position)
+                            .then(|| CLASSIC[position.row][position.col])
+                    })
+                    .collect();
+
+                Some(Pagoda::new(width, height, weights))
+            }
+            _ => None,
+        }
+    }
+
+    /**
+     * The classic pagoda for the grid's variant, if it has one.
+     */
+    pub fn for_grid(grid: &Grid) -> Option<Self> {
+        grid.variant
+            .and_then(Pagoda::classic)
+            .filter(|pagoda| pagoda.fits(grid))
+    }
+
+    pub fn weight(&self, position: &Position) -> Option<i32> {
+        if position.row >= self.height || position.col >= self.width {
+            return None;
+        }
+
+        self.weights[position.row * self.width + position.col]
+    }
+
+    /**
+     * Whether the pagoda weighs exactly the holes of the grid.
+     */
+    pub fn fits(&self, grid: &Grid) -> bool {
+        self.width == grid.width
+            && self.height == grid.height
+            && grid
+                .tiles
+                .iter()
+                .zip(self.weights.iter())
+                .all(|(tile, weight)| tile.is_corner == weight.is_none())
+    }
+
+    /**
+     * Whether no jump between holes of the pagoda increases its value.
+     */
+    pub fn is_valid(&self) -> bool {
+        let directions = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        (0..self.weights.len()).all(|index| {
+            let (row, col) = ((index / self.width) as i64, (index % self.width) as i64);
+
+            directions.iter().all(|(row_step, col_step)| {
+                let weight_at = |distance: i64| {
+                    let (row, col) = (row + distance * row_step, col + distance * col_step);
+                    if row < 0 || col < 0 {
+                        return None;
+                    }
+
+                    self.weight(&Position {
+                        row: row as usize,
+                        col: col as usize,
+                    })
+                };
+
+                match (weight_at(0), weight_at(1), weight_at(2)) {
+                    (Some(origin), Some(middle), Some(target)) => target <= origin + middle,
+                    _ => true,
+                }
+            })
+        })
+    }
+
+    /**
+     * Total weight of the tokens on the grid.
+     */
+    pub fn value(&self, grid: &Grid) -> i32 {
+        grid.tiles
+            .iter()
+            .filter(|tile| tile.has_token)
+            .filter_map(|tile| self.weight(&tile.position))
+            .sum()
+    }
+
+    /**
+     * Total weight of the tokens on a bitboard of the same board.
+     */
+    pub fn bitboard_value(&self, board: Bitboard) -> i32 {
+        let mut value = 0;
+        let mut pegs = board.0;
+        while pegs != 0 {
+            let index = pegs.trailing_zeros() as usize;
+            pegs &= pegs - 1;
+
+            value += self.weights.get(index).copied().flatten().unwrap_or(0);
+        }
+
+        value
+    }
+}
+
+/**
+ * Draws the weights as a grid, leaving the positions that are not holes blank.
+ */
+impl fmt::Display for Pagoda {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self
+            .weights
+            .chunks(self.width)
+            .map(|row| {
+                let line: String = row
+                    .iter()
+                    .map(|weight| match weight {
+                        Some(weight) => format!("{:>3}", weight),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+/**
+ * Conway's rule-of-three class of a position. Colour the holes with the three values of
+ * `(row + col) % 3`; every jump takes a token away from two colours and adds one to the third,
+ * which flips the parity of all three counts at once. Whether two of the counts have equal
+ * parity therefore never changes. The same holds for `(row - col) % 3`, which gives four bits and
+ * sixteen classes that no jump leaves.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PositionClass(u8);
+
+impl PositionClass {
+    pub fn of<'a>(positions: impl IntoIterator<Item = &'a Position>) -> Self {
+        let mut sums = [false; 3];
+        let mut differences = [false; 3];
+
+        for position in positions {
+            sums[(position.row + position.col) % 3] ^= true;
+            // Adding twice the column is the same as subtracting it modulo 3.
+            differences[(position.row + 2 * position.col) % 3] ^= true;
+        }
+
+        let bits = [
+            sums[0] != sums[1],
+            sums[1] != sums[2],
+            differences[0] != differences[1],
+            differences[1] != differences[2],
+        ];
+
+        PositionClass(
+            bits.iter()
+                .enumerate()
+                .fold(0, |class, (bit, set)| class | (*set as u8) << bit),
+        )
+    }
+
+    /**
+     * Number of the class, from 0 to 15. The class of the empty board is 0.
+     */
+    pub fn index(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for PositionClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Grid {
+    pub fn position_class(&self) -> PositionClass {
+        PositionClass::of(
+            self.tiles
+                .iter()
+                .filter(|tile| tile.has_token)
+                .map(|tile| &tile.position),
+        )
+    }
+
+    /**
+     * Quick check whether the target's tokens might be reached from this position. A `false` is
+     * final, while a `true` only means that neither the token counts nor the position classes rule
+     * it out.
+     */
+    pub fn could_reach(&self, target: &Grid) -> bool {
+        target.token_count() <= self.token_count()
+            && target.position_class() == self.position_class()
+    }
+}
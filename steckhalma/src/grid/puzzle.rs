@@ -0,0 +1,152 @@
+use crate::prelude::*;
+
+/**
+ * A problem that starts from a given pattern of tokens instead of a full board, and is solved by
+ * reaching a given pattern instead of a single token. Both patterns are indexed like
+ * `Grid::tiles` on the variant's board. Puzzles are shared in packs, see `puzzle_pack.rs`.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub name: String,
+    pub variant: BoardVariant,
+    pub start: Vec<bool>,
+    pub goal: Vec<bool>,
+    // Number of moves to aim for, counting a chain of jumps by the same token as one move.
+    pub par: Option<usize>,
+    // Moves known to solve the puzzle, checked by `with_solution`.
+    pub solution: Option<Vec<ValidMove>>,
+}
+
+impl Puzzle {
+    /**
+     * Checks that both patterns fit the board and only hold tokens in its holes.
+     */
+    pub fn new(
+        name: &str,
+        variant: BoardVariant,
+        start: Vec<bool>,
+        goal: Vec<bool>,
+    ) -> Result<Self, PuzzleError> {
+        let board = Grid::from_variant(variant);
+
+        for pattern in [&start, &goal] {
+            if pattern.len() != board.tiles.len() {
+                return Err(PuzzleError::WrongSize {
+                    expected: board.tiles.len(),
+                    found: pattern.len(),
+                });
+            }
+
+            let corner = board
+                .tiles
+                .iter()
+                .zip(pattern.iter())
+                .find(|(tile, has_token)| tile.is_corner && **has_token);
+            if let Some((tile, _)) = corner {
+                return Err(PuzzleError::NotAHole(tile.position));
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            variant,
+            start,
+            goal,
+            par: None,
+            solution: None,
+        })
+    }
+
+    /**
+     * Adds the reference solution after checking that it is legal and reaches the goal.
+     */
+    pub fn with_solution(mut self, solution: Vec<ValidMove>) -> Result<Self, PuzzleError> {
+        let mut grid = self.grid();
+        for valid_move in solution.iter() {
+            grid.apply_move(valid_move)
+                .map_err(PuzzleError::IllegalSolution)?;
+        }
+
+        if !Goal::Pattern(self.goal.clone()).is_reached(&grid) {
+            return Err(PuzzleError::IncompleteSolution);
+        }
+
+        self.solution = Some(solution);
+        Ok(self)
+    }
+
+    /**
+     * Reads both patterns from boards drawn in the format of `text.rs`, which have to draw the
+     * variant's holes.
+     */
+    pub fn from_drawings(
+        name: &str,
+        variant: BoardVariant,
+        start: &str,
+        goal: &str,
+    ) -> Result<Self, PuzzleError> {
+        let (width, height) = variant.size();
+        let pattern = |drawing: &str| -> Result<Vec<bool>, PuzzleError> {
+            let grid: Grid = drawing.parse()?;
+            if (grid.width, grid.height) != (width, height) {
+                return Err(PuzzleError::WrongSize {
+                    expected: width * height,
+                    found: grid.tiles.len(),
+                });
+            }
+
+            let mismatch = grid
+                .tiles
+                .iter()
+                .find(|tile| tile.is_corner == variant.is_hole(&tile.position));
+            if let Some(tile) = mismatch {
+                return Err(PuzzleError::WrongBoard(tile.position));
+            }
+
+            Ok(grid.tiles.iter().map(|tile| tile.has_token).collect())
+        };
+
+        Puzzle::new(name, variant, pattern(start)?, pattern(goal)?)
+    }
+
+    /**
+     * The classic problems of the English board, each ending with a single token in the center.
+     */
+    pub fn classics() -> Vec<Puzzle> {
+        Puzzle::parse_pack(include_str!("classics.puzzles")).unwrap()
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Puzzle::classics()
+            .into_iter()
+            .find(|puzzle| puzzle.name.to_lowercase() == name.to_lowercase())
+    }
+
+    /**
+     * A new game of the puzzle, won once the tokens form the goal pattern.
+     */
+    pub fn grid(&self) -> Grid {
+        let mut grid = self.pattern_grid(&self.start);
+        grid.pattern = Some(self.goal.clone());
+
+        grid
+    }
+
+    /**
+     * The puzzle's board with tokens in the holes marked in the pattern.
+     */
+    pub fn pattern_grid(&self, pattern: &[bool]) -> Grid {
+        let mut grid = Grid::from_variant(self.variant);
+        for (index, has_token) in pattern.iter().enumerate() {
+            let position = grid.from_index(index);
+            let is_corner = grid.tile_from_position(&position).is_corner;
+            grid.update_tile(&position, is_corner, *has_token);
+        }
+
+        grid
+    }
+
+    pub fn token_count(&self) -> usize {
+        self.start.iter().filter(|has_token| **has_token).count()
+    }
+}
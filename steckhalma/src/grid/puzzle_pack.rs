@@ -0,0 +1,164 @@
+use crate::prelude::*;
+
+/**
+ * Puzzle packs are plain text files listing puzzles one after another. Each puzzle starts with its
+ * name, followed by its board, an optional par and reference solution, and its start and goal
+ * patterns drawn in the format of `text.rs`, e.g.
+ *
+ * ```text
+ * # Lines starting with `#` are comments.
+ * name: Cross
+ * board: english
+ * par: 5
+ * solution: d3-b3 d5-d3 e3-c3 b3-d3 d2-d4
+ * start:
+ *   ...
+ *   .o.
+ * ..ooo..
+ * ...o...
+ * ...o...
+ *   ...
+ *   ...
+ * goal:
+ *   ...
+ *   ...
+ * .......
+ * ...o...
+ * .......
+ *   ...
+ *   ...
+ * ```
+ *
+ * The solution is written in algebraic notation, see `notation.rs`, and has to reach the goal.
+ */
+impl Puzzle {
+    pub fn to_pack_string(&self) -> String {
+        let mut text = format!("name: {}\nboard: {}\n", self.name, self.variant.name());
+        if let Some(par) = self.par {
+            text.push_str(&format!("par: {}\n", par));
+        }
+        if let Some(solution) = &self.solution {
+            text.push_str(&format!(
+                "solution: {}\n",
+                format_moves(solution, Notation::Algebraic)
+            ));
+        }
+
+        format!(
+            "{}start:\n{}\ngoal:\n{}\n",
+            text,
+            self.pattern_grid(&self.start),
+            self.pattern_grid(&self.goal)
+        )
+    }
+
+    pub fn format_pack(puzzles: &[Puzzle]) -> String {
+        puzzles
+            .iter()
+            .map(|puzzle| puzzle.to_pack_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn parse_pack(text: &str) -> Result<Vec<Puzzle>, PuzzleError> {
+        let mut entries: Vec<PackEntry> = Vec::new();
+        let mut drawing: Option<&str> = None;
+
+        for line in text.lines().filter(|line| !line.starts_with('#')) {
+            let (key, value) = match line.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => {
+                    // Drawings run until the next field.
+                    if let (Some(key), Some(entry)) = (drawing, entries.last_mut()) {
+                        entry.drawing(key).push_str(&format!("{}\n", line));
+                    }
+                    continue;
+                }
+            };
+
+            drawing = None;
+            if key == "name" {
+                entries.push(PackEntry::new(value));
+                continue;
+            }
+
+            let entry = entries
+                .last_mut()
+                .ok_or(PuzzleError::MissingField("name"))?;
+            match key {
+                "board" => entry.board = Some(value),
+                "par" => entry.par = Some(value),
+                "solution" => entry.solution = Some(value),
+                "start" | "goal" => drawing = Some(key),
+                _ => (),
+            }
+        }
+
+        entries
+            .iter()
+            .map(|entry| {
+                entry.to_puzzle().map_err(|error| PuzzleError::InPuzzle {
+                    name: entry.name.to_string(),
+                    error: Box::new(error),
+                })
+            })
+            .collect()
+    }
+}
+
+/**
+ * The fields of one puzzle as read from a pack, before they are checked.
+ */
+struct PackEntry<'a> {
+    name: &'a str,
+    board: Option<&'a str>,
+    par: Option<&'a str>,
+    solution: Option<&'a str>,
+    start: String,
+    goal: String,
+}
+
+impl<'a> PackEntry<'a> {
+    fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            board: None,
+            par: None,
+            solution: None,
+            start: String::new(),
+            goal: String::new(),
+        }
+    }
+
+    fn drawing(&mut self, key: &str) -> &mut String {
+        match key {
+            "start" => &mut self.start,
+            _ => &mut self.goal,
+        }
+    }
+
+    fn to_puzzle(&self) -> Result<Puzzle, PuzzleError> {
+        let board = self.board.ok_or(PuzzleError::MissingField("board"))?;
+        let variant =
+            BoardVariant::from_name(board).ok_or(PuzzleError::UnknownBoard(board.to_string()))?;
+
+        for (field, drawing) in [("start", &self.start), ("goal", &self.goal)] {
+            if drawing.trim().is_empty() {
+                return Err(PuzzleError::MissingField(field));
+            }
+        }
+
+        let mut puzzle = Puzzle::from_drawings(self.name, variant, &self.start, &self.goal)?;
+        if let Some(par) = self.par {
+            let par = par
+                .parse()
+                .map_err(|_| PuzzleError::InvalidPar(par.to_string()))?;
+            puzzle.par = Some(par);
+        }
+        if let Some(solution) = self.solution {
+            puzzle = puzzle.with_solution(parse_moves(solution)?)?;
+        }
+
+        Ok(puzzle)
+    }
+}
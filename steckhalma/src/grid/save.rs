@@ -0,0 +1,191 @@
+use std::fs;
+use std::path::Path;
+
+use crate::prelude::*;
+
+/**
+ * Saved games are plain text: the board variant, the moves played so far and the current position
+ * drawn in the format of `text.rs`, e.g.
+ *
+ * ```text
+ * board: english
+ * history: d2-d4 d5-d3
+ * hints: 1
+ * finish: d1
+ * goal: c3 d3 e3
+ * position:
+ *   ooo
+ *   o.o
+ * ooooooo
+ * ooo.ooo
+ * ooo.ooo
+ *   ooo
+ *   ooo
+ * ```
+ *
+ * Moves are written in algebraic notation, see `notation.rs`. Hints are listed by the number of
+ * moves played when they were given, at most the length of the history, and may be left out. So
+ * may the finish hole when it is the center, and the holes of the goal pattern when the game is
+ * not a puzzle.
+ */
+impl Grid {
+    pub fn to_save_string(&self) -> String {
+        let board = self.variant.map_or("custom", |variant| variant.name());
+        let hints = match self.hints.is_empty() {
+            true => String::new(),
+            false => format!(
+                "hints: {}\n",
+                self.hints
+                    .iter()
+                    .map(|hint| hint.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+        };
+
+        let finish = match self.finish == self.center() {
+            true => String::new(),
+            false => format!("finish: {}\n", self.finish.to_notation(Notation::Algebraic)),
+        };
+
+        let goal = match &self.pattern {
+            Some(pattern) => format!(
+                "goal: {}\n",
+                pattern
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, has_token)| **has_token)
+                    .map(|(index, _)| self.from_index(index).to_notation(Notation::Algebraic))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            "board: {}\nhistory: {}\n{}{}{}position:\n{}\n",
+            board,
+            format_moves(&self.history, Notation::Algebraic),
+            hints,
+            finish,
+            goal,
+            self
+        )
+    }
+
+    pub fn from_save_string(text: &str) -> Result<Self, SaveError> {
+        let mut variant = None;
+        let mut history = None;
+        let mut hints: Vec<usize> = Vec::new();
+        let mut finish = None;
+        let mut goal = None;
+        let mut position = None;
+
+        let mut lines = text.lines();
+        while let Some(line) = lines.next() {
+            let (key, value) = match line.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+
+            match key {
+                "board" if value == "custom" => variant = Some(None),
+                "board" => match BoardVariant::from_name(value) {
+                    Some(board) => variant = Some(Some(board)),
+                    None => return Err(SaveError::UnknownBoard(value.to_string())),
+                },
+                "history" => history = Some(parse_moves(value)?),
+                "hints" => {
+                    for hint in value.split_whitespace() {
+                        hints.push(
+                            hint.parse()
+                                .map_err(|_| SaveError::InvalidHint(hint.to_string()))?,
+                        );
+                    }
+                }
+                "finish" => finish = Some(value.to_string()),
+                "goal" => goal = Some(value.to_string()),
+                "position" => {
+                    position = Some(lines.by_ref().collect::<Vec<&str>>().join("\n"));
+                }
+                _ => (),
+            }
+        }
+
+        let variant = variant.ok_or(SaveError::MissingField("board"))?;
+        let history = history.ok_or(SaveError::MissingField("history"))?;
+        let position = position.ok_or(SaveError::MissingField("position"))?;
+
+        let mut grid: Grid = position.parse()?;
+        grid.variant = variant;
+        grid.history = history;
+        grid.check_history()?;
+
+        // Hints outlive the moves they were given after, just like on undo.
+        let moves_played = grid.history.len();
+        grid.hints = hints
+            .into_iter()
+            .map(|hint| hint.min(moves_played))
+            .collect();
+
+        if let Some(finish) = finish {
+            let invalid = || SaveError::InvalidFinish(finish.clone());
+            let position = Position::from_notation(&finish).map_err(|_| invalid())?;
+            grid = grid.with_finish(&position).map_err(|_| invalid())?;
+        }
+
+        if let Some(goal) = goal {
+            let mut pattern = vec![false; grid.tiles.len()];
+            for hole in goal.split_whitespace() {
+                let invalid = || SaveError::InvalidGoal(hole.to_string());
+                let position = Position::from_notation(hole).map_err(|_| invalid())?;
+                match grid.try_tile_from_position(&position) {
+                    Ok(tile) if !tile.is_corner => pattern[grid.to_index(&position)] = true,
+                    _ => return Err(invalid()),
+                }
+            }
+            grid.pattern = Some(pattern);
+        }
+
+        Ok(grid)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SaveError> {
+        fs::write(path, self.to_save_string())?;
+
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SaveError> {
+        Grid::from_save_string(&fs::read_to_string(path)?)
+    }
+
+    /**
+     * Undoes the whole history on a copy of the grid, to make sure every move can be undone later.
+     */
+    fn check_history(&self) -> Result<(), SaveError> {
+        let mut replay = self.clone();
+
+        while let Some(jump) = replay.history.last().copied() {
+            let has_token = |position: &Position, expected: bool| {
+                replay
+                    .try_tile_from_position(position)
+                    .is_ok_and(|tile| !tile.is_corner && tile.has_token == expected)
+            };
+
+            if !has_token(&jump.target, true)
+                || !has_token(&jump.origin, false)
+                || !has_token(&jump.middle, false)
+            {
+                return Err(SaveError::InconsistentHistory(jump));
+            }
+
+            replay.undo();
+            replay
+                .validate_move(&jump)
+                .map_err(|_| SaveError::InconsistentHistory(jump))?;
+        }
+
+        Ok(())
+    }
+}
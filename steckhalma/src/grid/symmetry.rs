@@ -0,0 +1,273 @@
+use crate::prelude::*;
+
+/**
+ * The eight rotations and reflections of a square grid.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    Identity,
+    // Clockwise rotations.
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    // Mirrors the columns, so left and right swap.
+    FlipHorizontal,
+    // Mirrors the rows, so top and bottom swap.
+    FlipVertical,
+    // Reflects along the diagonal from the top left to the bottom right.
+    FlipDiagonal,
+    // Reflects along the diagonal from the top right to the bottom left.
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+
+    /**
+     * Where the symmetry takes a position on a grid of the given size. Returns `None` for the
+     * position or the symmetry not fitting the grid, as quarter turns and diagonal flips only
+     * work on square grids.
+     */
+    pub fn apply(&self, position: &Position, width: usize, height: usize) -> Option<Position> {
+        if position.row >= height || position.col >= width {
+            return None;
+        }
+
+        let (row, col) = (position.row, position.col);
+        let (last_row, last_col) = (height - 1, width - 1);
+
+        let (row, col) = match self {
+            Symmetry::Identity => (row, col),
+            Symmetry::Rotate180 => (last_row - row, last_col - col),
+            Symmetry::FlipHorizontal => (row, last_col - col),
+            Symmetry::FlipVertical => (last_row - row, col),
+            _ if width != height => return None,
+            Symmetry::Rotate90 => (col, last_row - row),
+            Symmetry::Rotate270 => (last_col - col, row),
+            Symmetry::FlipDiagonal => (col, row),
+            Symmetry::FlipAntiDiagonal => (last_col - col, last_row - row),
+        };
+
+        Some(Position { row, col })
+    }
+
+    pub fn apply_move(
+        &self,
+        valid_move: &ValidMove,
+        width: usize,
+        height: usize,
+    ) -> Option<ValidMove> {
+        Some(ValidMove {
+            origin: self.apply(&valid_move.origin, width, height)?,
+            middle: self.apply(&valid_move.middle, width, height)?,
+            target: self.apply(&valid_move.target, width, height)?,
+        })
+    }
+}
+
+impl Grid {
+    /**
+     * The symmetries that map the board's holes onto themselves, starting with the identity.
+     */
+    pub fn symmetries(&self) -> Vec<Symmetry> {
+        Symmetry::ALL
+            .iter()
+            .filter(|symmetry| {
+                self.tiles.iter().all(|tile| {
+                    symmetry
+                        .apply(&tile.position, self.width, self.height)
+                        .is_some_and(|position| {
+                            self.tile_from_position(&position).is_corner == tile.is_corner
+                        })
+                })
+            })
+            .copied()
+            .collect()
+    }
+
+    /**
+     * The position with every token moved by the symmetry, along with the history, the finish
+     * hole and the goal pattern. Panics if the symmetry does not map the board onto itself.
+     */
+    pub fn transformed(&self, symmetry: Symmetry) -> Grid {
+        assert!(
+            self.symmetries().contains(&symmetry),
+            "{:?} does not preserve the board",
+            symmetry
+        );
+
+        let mut grid = self.clone();
+        for tile in self.tiles.iter() {
+            let position = symmetry
+                .apply(&tile.position, self.width, self.height)
+                .unwrap();
+            grid.update_tile(&position, tile.is_corner, tile.has_token);
+        }
+
+        let transform = |moves: &Vec<ValidMove>| -> Vec<ValidMove> {
+            moves
+                .iter()
+                .map(|valid_move| {
+                    symmetry
+                        .apply_move(valid_move, self.width, self.height)
+                        .unwrap()
+                })
+                .collect()
+        };
+        grid.history = transform(&self.history);
+        grid.undone = transform(&self.undone);
+        grid.finish = symmetry
+            .apply(&self.finish, self.width, self.height)
+            .unwrap();
+        if let Some(pattern) = &self.pattern {
+            let mut transformed = vec![false; pattern.len()];
+            for (index, has_token) in pattern.iter().enumerate() {
+                let position = symmetry
+                    .apply(&self.from_index(index), self.width, self.height)
+                    .unwrap();
+                transformed[self.to_index(&position)] = *has_token;
+            }
+            grid.pattern = Some(transformed);
+        }
+
+        grid
+    }
+
+    /**
+     * The representative shared by all positions that are symmetric to this one, so they can be
+     * treated as identical. It is picked the same way as `BitboardLayout::canonical` picks it.
+     */
+    pub fn canonical(&self) -> Grid {
+        self.symmetries()
+            .into_iter()
+            .map(|symmetry| self.transformed(symmetry))
+            // Comparing from the last tile orders grids like the numeric value of their bitboards.
+            .min_by_key(|grid| {
+                grid.tiles
+                    .iter()
+                    .rev()
+                    .map(|tile| tile.has_token)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap()
+    }
+
+    pub fn is_symmetric_to(&self, other: &Grid) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.canonical().tiles == other.canonical().tiles
+    }
+}
+
+impl BitboardLayout {
+    /**
+     * The symmetries that map the layout's holes onto themselves, starting with the identity.
+     */
+    pub fn symmetries(&self) -> Vec<Symmetry> {
+        Symmetry::ALL
+            .iter()
+            .filter(|symmetry| self.transform(Bitboard(self.holes), **symmetry).0 == self.holes)
+            .copied()
+            .collect()
+    }
+
+    /**
+     * Where the symmetry takes the hole with the given bit index, or `None` if it does not fit.
+     */
+    pub fn transform_index(&self, index: usize, symmetry: Symmetry) -> Option<usize> {
+        symmetry
+            .apply(&self.to_position(index), self.width, self.height)
+            .map(|position| position.row * self.width + position.col)
+    }
+
+    /**
+     * The board with every token moved by the symmetry. Positions the symmetry does not fit map
+     * to an empty board.
+     */
+    pub fn transform(&self, board: Bitboard, symmetry: Symmetry) -> Bitboard {
+        let mut transformed = 0;
+        let mut pegs = board.0;
+        while pegs != 0 {
+            let index = pegs.trailing_zeros() as usize;
+            pegs &= pegs - 1;
+
+            match self.transform_index(index, symmetry) {
+                Some(index) => transformed |= 1 << index,
+                None => return Bitboard(0),
+            }
+        }
+
+        Bitboard(transformed)
+    }
+
+    /**
+     * The smallest of the boards the given symmetries take the position to.
+     */
+    pub fn canonical(&self, board: Bitboard, symmetries: &[Symmetry]) -> Bitboard {
+        symmetries
+            .iter()
+            .map(|symmetry| self.transform(board, *symmetry))
+            .min()
+            .unwrap_or(board)
+    }
+}
+
+/**
+ * A symmetry of a bitboard layout as lookup tables, which move the tokens of a whole byte of the
+ * board at once. Holes the symmetry does not fit are dropped.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitboardTransform {
+    // Image of every value of each byte of a bitboard, starting from the lowest byte.
+    bytes: Vec<[u128; 256]>,
+    // Image of every bit index.
+    indices: Vec<Option<usize>>,
+}
+
+impl BitboardTransform {
+    pub fn new(layout: &BitboardLayout, symmetry: Symmetry) -> Self {
+        let tiles = layout.width * layout.height;
+        let indices: Vec<Option<usize>> = (0..tiles)
+            .map(|index| layout.transform_index(index, symmetry))
+            .collect();
+
+        let bytes = (0..tiles.div_ceil(8))
+            .map(|byte| {
+                let mut table = [0; 256];
+                for (value, image) in table.iter_mut().enumerate() {
+                    for bit in (0..8).filter(|bit| value & (1 << bit) != 0) {
+                        if let Some(Some(index)) = indices.get(byte * 8 + bit) {
+                            *image |= 1 << index;
+                        }
+                    }
+                }
+
+                table
+            })
+            .collect();
+
+        Self { bytes, indices }
+    }
+
+    pub fn apply(&self, board: Bitboard) -> Bitboard {
+        let mut transformed = 0;
+        for (byte, table) in self.bytes.iter().enumerate() {
+            transformed |= table[(board.0 >> (byte * 8)) as usize & 0xff];
+        }
+
+        Bitboard(transformed)
+    }
+
+    pub fn apply_index(&self, index: usize) -> Option<usize> {
+        self.indices.get(index).copied().flatten()
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+pub const TOKEN: char = 'o';
+pub const HOLE: char = '.';
+pub const OFF_BOARD: char = ' ';
+
+/**
+ * Parses a board drawn as text, one line per row: `o` for a token, `.` for an empty hole and a
+ * space for positions off the board. Lines shorter than the longest one are padded with spaces,
+ * and blank lines before and after the board are ignored.
+ */
+impl FromStr for Grid {
+    type Err = GridError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = text.lines().collect();
+        let first = lines.iter().position(|line| !line.trim().is_empty());
+        let last = lines.iter().rposition(|line| !line.trim().is_empty());
+        let rows = match (first, last) {
+            (Some(first), Some(last)) => &lines[first..=last],
+            _ => return Err(GridError::EmptyBoard),
+        };
+
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = rows.len();
+        let mut holes = vec![false; width * height];
+        let mut tokens = vec![false; width * height];
+
+        for (row, line) in rows.iter().enumerate() {
+            for (col, character) in line.chars().enumerate() {
+                let index = row * width + col;
+
+                match character {
+                    TOKEN => {
+                        holes[index] = true;
+                        tokens[index] = true;
+                    }
+                    HOLE => holes[index] = true,
+                    OFF_BOARD => (),
+                    _ => {
+                        return Err(GridError::InvalidCharacter {
+                            row,
+                            col,
+                            character,
+                        })
+                    }
+                }
+            }
+        }
+
+        let mut grid = Grid::from_mask(width, height, &holes);
+        for (index, has_token) in tokens.iter().enumerate() {
+            if holes[index] && !has_token {
+                let position = grid.from_index(index);
+                grid.update_tile(&position, false, false);
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+/**
+ * Draws the board in the format read by `FromStr`, without trailing spaces.
+ */
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self
+            .tiles
+            .chunks(self.width)
+            .map(|row| {
+                let line: String = row
+                    .iter()
+                    .map(|tile| match tile {
+                        tile if tile.is_corner => OFF_BOARD,
+                        tile if tile.has_token => TOKEN,
+                        _ => HOLE,
+                    })
+                    .collect();
+
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
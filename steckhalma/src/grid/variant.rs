@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardVariant {
+    // 33-hole cross on a 7x7 grid.
+    English,
+    // 37-hole board on a 7x7 grid, the English cross with the inner corners filled in.
+    French,
+    // 45-hole cross on a 9x9 grid, as sold in Germany. Shares its layout with `Wiegleb`.
+    German,
+    // 45-hole cross on a 9x9 grid, described by J. C. Wiegleb in 1779.
+    Wiegleb,
+    // 39-hole cross on an 8x8 grid, with arms of 3, 3, 2 and 2 rows.
+    Asymmetric,
+    // 41-hole diamond on a 9x9 grid.
+    Diamond,
+}
+
+impl BoardVariant {
+    pub const ALL: [BoardVariant; 6] = [
+        BoardVariant::English,
+        BoardVariant::French,
+        BoardVariant::German,
+        BoardVariant::Wiegleb,
+        BoardVariant::Asymmetric,
+        BoardVariant::Diamond,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BoardVariant::English => "english",
+            BoardVariant::French => "french",
+            BoardVariant::German => "german",
+            BoardVariant::Wiegleb => "wiegleb",
+            BoardVariant::Asymmetric => "asymmetric",
+            BoardVariant::Diamond => "diamond",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        BoardVariant::ALL
+            .iter()
+            .find(|variant| variant.name() == name.to_lowercase())
+            .copied()
+    }
+
+    /**
+     * Width and height of the grid the board is laid out on.
+     */
+    pub fn size(&self) -> (usize, usize) {
+        match self {
+            BoardVariant::English | BoardVariant::French => (7, 7),
+            BoardVariant::Asymmetric => (8, 8),
+            BoardVariant::German | BoardVariant::Wiegleb | BoardVariant::Diamond => (9, 9),
+        }
+    }
+
+    pub fn is_hole(&self, position: &Position) -> bool {
+        let (width, height) = self.size();
+        if position.row >= height || position.col >= width {
+            return false;
+        }
+
+        let row = position.row as i32;
+        let col = position.col as i32;
+        // Distance from the center row and column.
+        let row_offset = (row - height as i32 / 2).abs();
+        let col_offset = (col - width as i32 / 2).abs();
+
+        match self {
+            BoardVariant::English
+            | BoardVariant::German
+            | BoardVariant::Wiegleb
+            | BoardVariant::Asymmetric => row_offset <= 1 || col_offset <= 1,
+            BoardVariant::French => {
+                row_offset + col_offset <= 4 && (row_offset < 3 || col_offset < 3)
+            }
+            BoardVariant::Diamond => row_offset + col_offset <= 4,
+        }
+    }
+
+    /**
+     * The hole left empty in the standard starting position. The center of the French and the
+     * Diamond board is in the wrong position class for any single token to remain.
+     */
+    pub fn vacancy(&self) -> Position {
+        match self {
+            BoardVariant::French => Position { row: 1, col: 3 },
+            BoardVariant::Diamond => Position { row: 2, col: 4 },
+            _ => self.center(),
+        }
+    }
+
+    /**
+     * The hole the last token has to end up in from the standard starting position. Where it is
+     * not the center, a solution is recorded in the tests.
+     */
+    pub fn finish(&self) -> Position {
+        match self {
+            BoardVariant::French => Position { row: 5, col: 3 },
+            BoardVariant::Diamond => Position { row: 6, col: 4 },
+            _ => self.center(),
+        }
+    }
+
+    fn center(&self) -> Position {
+        let (width, height) = self.size();
+
+        Position {
+            row: height / 2,
+            col: width / 2,
+        }
+    }
+}
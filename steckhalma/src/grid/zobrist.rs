@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+const ZOBRIST_SEED: u64 = 0x5374_6563_6b68_616c;
+
+/**
+ * Random key for a token in the tile with the given index. The keys follow the splitmix64
+ * sequence, so every grid agrees on them without sharing a table.
+ */
+pub fn zobrist_key(index: usize) -> u64 {
+    let mut key = ZOBRIST_SEED.wrapping_add((index as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    key = (key ^ (key >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    key = (key ^ (key >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    key ^ (key >> 31)
+}
+
+impl Grid {
+    /**
+     * Hash of where the tokens are, kept up to date by `update_tile`. Grids with equal tokens have
+     * equal hashes, so comparing hashes rules out most differing positions in constant time.
+     */
+    pub fn zobrist_hash(&self) -> u64 {
+        self.zobrist
+    }
+
+    /**
+     * The hash recomputed from all tiles, which always matches `zobrist_hash`.
+     */
+    pub fn compute_zobrist_hash(&self) -> u64 {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| tile.has_token)
+            .fold(0, |hash, (index, _)| hash ^ zobrist_key(index))
+    }
+}
@@ -0,0 +1,24 @@
+pub mod grid;
+pub mod solver;
+
+pub mod prelude {
+    pub use crate::grid::bitboard::*;
+    pub use crate::grid::error::*;
+    pub use crate::grid::game_move::*;
+    pub use crate::grid::game_result::*;
+    pub use crate::grid::notation::*;
+    pub use crate::grid::pagoda::*;
+    pub use crate::grid::position::*;
+    pub use crate::grid::position_class::*;
+    pub use crate::grid::puzzle::*;
+    pub use crate::grid::symmetry::*;
+    pub use crate::grid::tile::*;
+    pub use crate::grid::variant::*;
+    pub use crate::grid::zobrist::*;
+    pub use crate::grid::*;
+    pub use crate::solver::complement::*;
+    pub use crate::solver::goal::*;
+    pub use crate::solver::minimum::*;
+    pub use crate::solver::review::*;
+    pub use crate::solver::*;
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+/**
+ * For every hole of a board, the holes a single token can end up in when the game starts with
+ * only that hole empty. Starting and finishing in the same hole is a complement problem.
+ */
+pub struct ComplementTable {
+    // Each starting vacancy with the holes the last token can finish in, in board order.
+    pub finishes: Vec<(Position, Vec<Position>)>,
+}
+
+impl ComplementTable {
+    /**
+     * Solves every single-vacancy-to-single-survivor problem on the grid's board. The tokens on
+     * the grid do not matter. Fails for boards too large to search.
+     */
+    pub fn new(board: &Grid) -> Result<Self, GridError> {
+        let holes: Vec<Position> = board
+            .tiles
+            .iter()
+            .filter(|tile| !tile.is_corner)
+            .map(|tile| tile.position)
+            .collect();
+
+        // Only solve one vacancy of every set of vacancies that are symmetric to each other.
+        let symmetries = board.symmetries();
+        let image = |symmetry: &Symmetry, position: &Position| {
+            symmetry.apply(position, board.width, board.height).unwrap()
+        };
+        let representatives: Vec<Position> = holes
+            .iter()
+            .filter(|vacancy| {
+                symmetries.iter().all(|symmetry| {
+                    let other = image(symmetry, vacancy);
+                    board.to_index(&other) >= board.to_index(vacancy)
+                })
+            })
+            .copied()
+            .collect();
+
+        let mut solved: Vec<(Position, Vec<Position>)> = representatives
+            .iter()
+            .map(|vacancy| (*vacancy, Vec::new()))
+            .collect();
+
+        // A solver's dead ends only depend on its goal, so share one solver per finishing hole.
+        for finish in holes.iter() {
+            let mut solver = Solver::new(Goal::SinglePegAt(*finish));
+
+            for (vacancy, reachable) in solved.iter_mut() {
+                let start = board.with_vacancy(vacancy).unwrap();
+                if solver.solve(&start)?.is_some() {
+                    reachable.push(*finish);
+                }
+            }
+        }
+
+        let finishes = holes
+            .iter()
+            .map(|vacancy| {
+                let (symmetry, reachable) = symmetries
+                    .iter()
+                    .find_map(|symmetry| {
+                        solved
+                            .iter()
+                            .find(|(solved, _)| image(symmetry, solved) == *vacancy)
+                            .map(|(_, reachable)| (symmetry, reachable))
+                    })
+                    .unwrap();
+
+                let mut reachable: Vec<Position> = reachable
+                    .iter()
+                    .map(|finish| image(symmetry, finish))
+                    .collect();
+                reachable.sort_by_key(|finish| board.to_index(finish));
+
+                (*vacancy, reachable)
+            })
+            .collect();
+
+        Ok(Self { finishes })
+    }
+
+    pub fn finishes_from(&self, vacancy: &Position) -> Option<&Vec<Position>> {
+        self.finishes
+            .iter()
+            .find(|(start, _)| start == vacancy)
+            .map(|(_, finishes)| finishes)
+    }
+
+    /**
+     * The holes whose complement problem is solvable.
+     */
+    pub fn complements(&self) -> Vec<Position> {
+        self.finishes
+            .iter()
+            .filter(|(vacancy, finishes)| finishes.contains(vacancy))
+            .map(|(vacancy, _)| *vacancy)
+            .collect()
+    }
+}
+
+/**
+ * One line per starting vacancy with the finishing holes in algebraic notation, the complement
+ * marked with a `*`, e.g. `d1: d1* d4 d7`.
+ */
+impl fmt::Display for ComplementTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines: Vec<String> = self
+            .finishes
+            .iter()
+            .map(|(vacancy, finishes)| {
+                let finishes: Vec<String> = finishes
+                    .iter()
+                    .map(|finish| match finish == vacancy {
+                        true => format!("{}*", finish.to_notation(Notation::Algebraic)),
+                        false => finish.to_notation(Notation::Algebraic),
+                    })
+                    .collect();
+
+                format!(
+                    "{}: {}",
+                    vacancy.to_notation(Notation::Algebraic),
+                    finishes.join(" ")
+                )
+                .trim_end()
+                .to_string()
+            })
+            .collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Goal {
+    // A single token left anywhere on the board.
+    SinglePeg,
+    // A single token left in the given hole.
+    SinglePegAt(Position),
+    // Tokens in exactly the holes marked in the pattern, which is indexed like `Grid::tiles`.
+    Pattern(Vec<bool>),
+}
+
+impl Goal {
+    /**
+     * The goal of the game on the grid: the puzzle's pattern if it has one, otherwise a single
+     * token in the finish hole.
+     */
+    pub fn for_grid(grid: &Grid) -> Self {
+        match &grid.pattern {
+            Some(pattern) => Goal::Pattern(pattern.clone()),
+            None => Goal::SinglePegAt(grid.finish),
+        }
+    }
+
+    /**
+     * The same goal with the hole of the last token left open, to tell whether a game can still be
+     * won at all.
+     */
+    pub fn relaxed(&self) -> Self {
+        match self {
+            Goal::SinglePegAt(_) => Goal::SinglePeg,
+            goal => goal.clone(),
+        }
+    }
+
+    /**
+     * The goal of ending up with the tokens exactly where they are on the given grid.
+     */
+    pub fn pattern_of(grid: &Grid) -> Self {
+        Goal::Pattern(grid.tiles.iter().map(|tile| tile.has_token).collect())
+    }
+
+    pub fn token_count(&self) -> usize {
+        match self {
+            Goal::SinglePeg | Goal::SinglePegAt(_) => 1,
+            Goal::Pattern(pattern) => pattern.iter().filter(|has_token| **has_token).count(),
+        }
+    }
+
+    pub fn is_reached(&self, grid: &Grid) -> bool {
+        match self {
+            Goal::SinglePeg => grid.token_count() == 1,
+            Goal::SinglePegAt(position) => {
+                grid.token_count() == 1 && grid.tile_from_position(position).has_token
+            }
+            Goal::Pattern(pattern) => grid
+                .tiles
+                .iter()
+                .zip(pattern.iter())
+                .all(|(tile, has_token)| tile.has_token == *has_token),
+        }
+    }
+
+    /**
+     * Same as `is_reached`, for a position on the given bitboard layout.
+     */
+    pub fn is_reached_on(&self, layout: &BitboardLayout, board: Bitboard) -> bool {
+        match self.bitboard(layout) {
+            Some(target) => board == target,
+            None => board.token_count() == 1,
+        }
+    }
+
+    /**
+     * The tokens of the goal position on a bitboard, or `None` if the goal leaves them open.
+     */
+    pub fn bitboard(&self, layout: &BitboardLayout) -> Option<Bitboard> {
+        match self {
+            Goal::SinglePeg => None,
+            Goal::SinglePegAt(position) => {
+                Some(Bitboard(1 << (position.row * layout.width + position.col)))
+            }
+            Goal::Pattern(pattern) => Some(Bitboard(
+                pattern
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, has_token)| **has_token)
+                    .fold(0, |board, (index, _)| board | 1 << index),
+            )),
+        }
+    }
+
+    /**
+     * Whether a position reaches the goal exactly when its image under the symmetry does.
+     */
+    pub fn is_invariant_under(&self, symmetry: Symmetry, layout: &BitboardLayout) -> bool {
+        match self {
+            Goal::SinglePeg => true,
+            Goal::SinglePegAt(position) => {
+                symmetry.apply(position, layout.width, layout.height) == Some(*position)
+            }
+            Goal::Pattern(_) => {
+                let board = self.bitboard(layout).unwrap();
+                layout.transform(board, symmetry) == board
+            }
+        }
+    }
+
+    /**
+     * The smallest value the pagoda takes on a position that reaches the goal, or `None` if the
+     * goal lies outside the pagoda's holes.
+     */
+    pub fn pagoda_value(&self, pagoda: &Pagoda) -> Option<i32> {
+        match self {
+            Goal::SinglePeg => pagoda.weights.iter().flatten().min().copied(),
+            Goal::SinglePegAt(position) => pagoda.weight(position),
+            Goal::Pattern(pattern) => pattern
+                .iter()
+                .zip(pagoda.weights.iter())
+                .filter(|(has_token, _)| **has_token)
+                .map(|(_, weight)| *weight)
+                .sum(),
+        }
+    }
+
+    /**
+     * Whether the pagoda proves that the goal cannot be reached from the grid's position.
+     */
+    pub fn is_ruled_out_by(&self, pagoda: &Pagoda, grid: &Grid) -> bool {
+        self.pagoda_value(pagoda)
+            .is_some_and(|value| pagoda.value(grid) < value)
+    }
+
+    /**
+     * The position class of the goal position on the grid's board, or `None` if the goal leaves
+     * the hole of the last token open.
+     */
+    pub fn position_class(&self, grid: &Grid) -> Option<PositionClass> {
+        match self {
+            Goal::SinglePeg => None,
+            Goal::SinglePegAt(position) => Some(PositionClass::of([position])),
+            Goal::Pattern(pattern) => {
+                let positions: Vec<Position> = pattern
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, has_token)| **has_token)
+                    .map(|(index, _)| grid.from_index(index))
+                    .collect();
+
+                Some(PositionClass::of(positions.iter()))
+            }
+        }
+    }
+
+    /**
+     * Whether the position class of the grid proves that the goal cannot be reached from it.
+     */
+    pub fn is_ruled_out_by_class(&self, grid: &Grid) -> bool {
+        let class = grid.position_class();
+
+        match self.position_class(grid) {
+            Some(goal_class) => goal_class != class,
+            None => grid
+                .tiles
+                .iter()
+                .filter(|tile| !tile.is_corner)
+                .all(|tile| PositionClass::of([&tile.position]) != class),
+        }
+    }
+}
+
+impl fmt::Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Goal::SinglePeg => write!(f, "a single token"),
+            Goal::SinglePegAt(position) => write!(
+                f,
+                "a single token in {}",
+                position.to_notation(Notation::Algebraic)
+            ),
+            Goal::Pattern(_) => write!(f, "the goal pattern"),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::prelude::*;
+
+/**
+ * Counts moves the way solitaire records do, where a chain of jumps by the same token is one move.
+ */
+pub fn count_moves(jumps: &[ValidMove]) -> usize {
+    MultiJump::group(jumps).len()
+}
+
+// Stands for no token jumping in a slot of `MoveBounds`.
+const NOT_JUMPING: u8 = u8::MAX;
+
+/**
+ * Largest numbers of moves known not to be enough, by canonical position and the token that may
+ * go on jumping for free. Every position has a single slot picked by its hash and pushes out
+ * whatever was stored there, so the table never grows beyond its slots. Losing a bound only costs
+ * searching the position again.
+ */
+#[derive(Default)]
+pub struct MoveBounds {
+    // The position, the jumping token and the bound. Slots holding an empty board are unused, as
+    // a position without tokens is never stored.
+    slots: Vec<(Bitboard, u8, u8)>,
+}
+
+impl MoveBounds {
+    // 2^21 slots of 32 bytes take 64 MiB.
+    const SLOT_BITS: u32 = 21;
+
+    pub fn get(&self, board: Bitboard, jumping: Option<usize>) -> Option<usize> {
+        let jumping = MoveBounds::encode(jumping);
+        self.slots
+            .get(MoveBounds::slot(board, jumping))
+            .filter(|(stored, stored_jumping, _)| *stored == board && *stored_jumping == jumping)
+            .map(|(_, _, bound)| *bound as usize)
+    }
+
+    pub fn insert(&mut self, board: Bitboard, jumping: Option<usize>, bound: usize) {
+        if self.slots.is_empty() {
+            self.slots = vec![(Bitboard(0), NOT_JUMPING, 0); 1 << MoveBounds::SLOT_BITS];
+        }
+
+        let jumping = MoveBounds::encode(jumping);
+        self.slots[MoveBounds::slot(board, jumping)] = (board, jumping, bound as u8);
+    }
+
+    /**
+     * Forgets every bound and gives the memory back.
+     */
+    pub fn clear(&mut self) {
+        self.slots = Vec::new();
+    }
+
+    // Bit indices and move counts stay below `BitboardLayout::MAX_TILES`, so they fit a byte.
+    fn encode(jumping: Option<usize>) -> u8 {
+        jumping.map_or(NOT_JUMPING, |index| index as u8)
+    }
+
+    fn slot(board: Bitboard, jumping: u8) -> usize {
+        let hash = (board.0 as u64) ^ ((board.0 >> 64) as u64).rotate_left(29) ^ jumping as u64;
+        (hash.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> (64 - MoveBounds::SLOT_BITS)) as usize
+    }
+}
+
+impl Solver {
+    /**
+     * Returns a solution with the fewest moves, counting a chain of jumps by the same token as one
+     * move, or `None` if the goal cannot be reached. Fails for boards too large to search.
+     *
+     * This is an iterative deepening search on the number of moves. Every full Merson region of
+     * the board has to be emptied by a move of its own before the end, unless the goal lets it
+     * stay, so counting them bounds how many moves are still needed. Budgets found too small are
+     * remembered for the canonical position under the symmetries that keep the goal.
+     */
+    pub fn solve_minimum_moves(
+        &mut self,
+        grid: &Grid,
+    ) -> Result<Option<Vec<ValidMove>>, GridError> {
+        let (layout, board) = match self.prepare(grid)? {
+            Some(prepared) => prepared,
+            None => return Ok(None),
+        };
+        let regions = layout.merson_regions();
+
+        // Every jump takes a token away, so there can be no more moves than that.
+        let most_moves = match board.token_count().checked_sub(self.goal.token_count()) {
+            Some(most_moves) => most_moves,
+            None => return Ok(None),
+        };
+        let mut jumps = Vec::new();
+        for budget in 0..=most_moves {
+            if self.is_stopped() {
+                break;
+            }
+
+            if self.search_minimum(&layout, &regions, board, None, budget, &mut jumps) {
+                return Ok(Some(
+                    jumps
+                        .iter()
+                        .map(|jump| layout.to_valid_move(jump))
+                        .collect(),
+                ));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /**
+     * Searches for a solution within `budget` moves, where `jumping` is the token that made the
+     * last jump and may continue its move.
+     */
+    fn search_minimum(
+        &mut self,
+        layout: &BitboardLayout,
+        regions: &[u128],
+        board: Bitboard,
+        jumping: Option<usize>,
+        budget: usize,
+        jumps: &mut Vec<Jump>,
+    ) -> bool {
+        if self.goal.is_reached_on(layout, board) {
+            return true;
+        }
+
+        if board.token_count() <= self.goal.token_count() || self.is_hopeless(board) {
+            return false;
+        }
+
+        if self.moves_needed(layout, regions, board, jumping) > budget {
+            return false;
+        }
+
+        let moves = layout.jumps(board);
+        // A token that cannot jump on is no different from one that stopped, which may need one
+        // more move.
+        let jumping = jumping.filter(|index| moves.iter().any(|jump| jump.origin == *index));
+        if jumping.is_none() && self.moves_needed(layout, regions, board, None) > budget {
+            return false;
+        }
+
+        let (key, key_jumping) = self.canonical_move_state(board, jumping);
+        if self
+            .move_bounds
+            .get(key, key_jumping)
+            .is_some_and(|bound| bound >= budget)
+        {
+            return false;
+        }
+
+        if self.is_stopped() {
+            return false;
+        }
+
+        self.nodes += 1;
+
+        // Going on with the jumping token is free, so it is tried first.
+        let is_free = |jump: &&Jump| jumping == Some(jump.origin);
+        let free = moves.iter().filter(is_free);
+        let paid = moves.iter().filter(|jump| !is_free(jump) && budget > 0);
+        for jump in free.chain(paid).copied() {
+            let cost = if jumping == Some(jump.origin) { 0 } else { 1 };
+
+            jumps.push(jump);
+            let next = layout.apply(board, &jump);
+            if self.search_minimum(
+                layout,
+                regions,
+                next,
+                Some(jump.target),
+                budget - cost,
+                jumps,
+            ) {
+                return true;
+            }
+
+            jumps.pop();
+        }
+
+        // Jumps skipped after a cancel or running out of positions do not prove the budget too small.
+        if !self.is_stopped() {
+            self.move_bounds.insert(key, key_jumping, budget);
+        }
+        false
+    }
+
+    /**
+     * A lower bound on the moves still needed to reach the goal, which is not reached yet.
+     */
+    fn moves_needed(
+        &self,
+        layout: &BitboardLayout,
+        regions: &[u128],
+        board: Bitboard,
+        jumping: Option<usize>,
+    ) -> usize {
+        let target = self.goal.bitboard(layout).map(|target| target.0);
+        // Moves start in one hole each, so every full region needs a move of its own, as long as
+        // the regions counted do not overlap. A region holding the jumping token may be emptied by
+        // the move already under way.
+        let mut counted = jumping.map_or(0, |index| 1 << index);
+        let mut needed = 0;
+        let mut may_stay = false;
+        for region in regions {
+            if board.0 & region != *region || region & counted != 0 {
+                continue;
+            }
+
+            // The goal keeps these tokens where they are.
+            if target.is_some_and(|target| region & !target == 0) {
+                continue;
+            }
+
+            counted |= region;
+            needed += 1;
+            // The last token may be the one in this hole.
+            may_stay |= target.is_none() && region.count_ones() == 1;
+        }
+        let needed = needed - may_stay as usize;
+
+        if jumping.is_none() {
+            needed.max(1)
+        } else {
+            needed
+        }
+    }
+}
@@ -0,0 +1,253 @@
+pub mod complement;
+pub mod goal;
+pub mod minimum;
+pub mod review;
+
+#[cfg(test)]
+#[path = "./solver_test.rs"]
+mod solver_test;
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+/**
+ * Exhaustive depth-first search for a sequence of moves from a position to the goal.
+ *
+ * The search runs on bitboards. Positions that were fully searched without reaching the goal are
+ * remembered, so they are never searched twice. Dead ends are stored in their canonical form under
+ * the symmetries of the board that keep the goal, so symmetric positions are only searched once.
+ * As these dead ends only depend on the goal and the board, a solver can be reused for other
+ * positions, and forgets them when it is given a position on another board. Where the board has a
+ * classic pagoda, positions it proves hopeless are cut off right away. Goals outside the position
+ * class of the start are rejected before searching at all.
+ *
+ * A search running in the background can be stopped through the flag given to `with_cancel`, and
+ * kept from growing without end through `with_node_limit`.
+ */
+pub struct Solver {
+    // Fixed for the lifetime of the solver, as the dead ends and move bounds only hold for it.
+    goal: Goal,
+    // Board the dead ends and move bounds were found on.
+    layout: Option<BitboardLayout>,
+    dead_ends: HashSet<Bitboard>,
+    // The symmetries of the board that keep the goal.
+    transforms: Vec<BitboardTransform>,
+    // Pagoda of the board and its value on the goal.
+    pagoda: Option<(Pagoda, i32)>,
+    // Only used when minimizing the number of moves.
+    move_bounds: MoveBounds,
+    // Number of positions searched so far.
+    pub nodes: usize,
+    // Number of positions to search before giving up.
+    node_limit: Option<usize>,
+    // Set from another thread to stop the search.
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Solver {
+    pub fn new(goal: Goal) -> Self {
+        Self {
+            goal,
+            layout: None,
+            dead_ends: HashSet::new(),
+            transforms: Vec::new(),
+            pagoda: None,
+            move_bounds: MoveBounds::default(),
+            nodes: 0,
+            node_limit: None,
+            cancel: None,
+        }
+    }
+
+    /**
+     * Stops searching once the flag is set. A cancelled search returns `None` as if the goal
+     * could not be reached, but remembers nothing it did not finish.
+     */
+    pub fn with_cancel(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /**
+     * Gives up once the given number of positions has been searched, which also bounds the memory
+     * taken by the dead ends. A search that gives up returns `None` like a cancelled one, and is
+     * told apart from an unreachable goal by `is_out_of_budget`.
+     */
+    pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = Some(node_limit);
+        self
+    }
+
+    pub fn goal(&self) -> &Goal {
+        &self.goal
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    /**
+     * Whether the solver searched as many positions as it was allowed to, so a `None` it returned
+     * is no proof.
+     */
+    pub fn is_out_of_budget(&self) -> bool {
+        self.node_limit.is_some_and(|limit| self.nodes >= limit)
+    }
+
+    /**
+     * Whether the search has to stop without finishing, because it was cancelled or ran out of
+     * positions to search.
+     */
+    fn is_stopped(&self) -> bool {
+        self.is_cancelled() || self.is_out_of_budget()
+    }
+
+    /**
+     * Returns the moves leading from the grid's position to the goal, or `None` if the goal
+     * cannot be reached from it. Fails for boards too large to search.
+     */
+    pub fn solve(&mut self, grid: &Grid) -> Result<Option<Vec<ValidMove>>, GridError> {
+        let (layout, board) = match self.prepare(grid)? {
+            Some(prepared) => prepared,
+            None => return Ok(None),
+        };
+
+        let mut jumps = Vec::new();
+        if self.search(&layout, board, &mut jumps) {
+            Ok(Some(
+                jumps
+                    .iter()
+                    .map(|jump| layout.to_valid_move(jump))
+                    .collect(),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /**
+     * The first move of a solution, as a hint for a player who is stuck.
+     */
+    pub fn hint(&mut self, grid: &Grid) -> Result<Option<ValidMove>, GridError> {
+        Ok(self.solve(grid)?.and_then(|moves| moves.first().copied()))
+    }
+
+    /**
+     * Sets the solver up for the grid's board, or returns `None` if the position class already
+     * rules the goal out. Fails for boards too large for a bitboard.
+     */
+    fn prepare(&mut self, grid: &Grid) -> Result<Option<(BitboardLayout, Bitboard)>, GridError> {
+        let layout = BitboardLayout::new(grid)?;
+        if self.goal.is_ruled_out_by_class(grid) {
+            return Ok(None);
+        }
+
+        // Bitboards only name positions together with their board.
+        if self.layout.as_ref() != Some(&layout) {
+            self.dead_ends.clear();
+            self.move_bounds.clear();
+            self.transforms = layout
+                .symmetries()
+                .into_iter()
+                .filter(|symmetry| self.goal.is_invariant_under(*symmetry, &layout))
+                .map(|symmetry| BitboardTransform::new(&layout, symmetry))
+                .collect();
+            self.layout = Some(layout.clone());
+        }
+
+        let board = layout.to_bitboard(grid);
+        self.pagoda = Pagoda::for_grid(grid).and_then(|pagoda| {
+            let value = self.goal.pagoda_value(&pagoda)?;
+            Some((pagoda, value))
+        });
+
+        Ok(Some((layout, board)))
+    }
+
+    /**
+     * The smallest of the boards the symmetries that keep the goal take the position to.
+     */
+    fn canonical(&self, board: Bitboard) -> Bitboard {
+        self.transforms
+            .iter()
+            .map(|transform| transform.apply(board))
+            .min()
+            .unwrap_or(board)
+    }
+
+    /**
+     * The smallest of the positions the symmetries that keep the goal take the board to, together
+     * with where they take the jumping token.
+     */
+    fn canonical_move_state(
+        &self,
+        board: Bitboard,
+        jumping: Option<usize>,
+    ) -> (Bitboard, Option<usize>) {
+        self.transforms
+            .iter()
+            .map(|transform| {
+                (
+                    transform.apply(board),
+                    jumping.and_then(|index| transform.apply_index(index)),
+                )
+            })
+            .min()
+            .unwrap_or((board, jumping))
+    }
+
+    /**
+     * Whether the pagoda proves that the goal cannot be reached from the board.
+     */
+    fn is_hopeless(&self, board: Bitboard) -> bool {
+        self.pagoda
+            .as_ref()
+            .is_some_and(|(pagoda, value)| pagoda.bitboard_value(board) < *value)
+    }
+
+    fn search(&mut self, layout: &BitboardLayout, board: Bitboard, jumps: &mut Vec<Jump>) -> bool {
+        if self.goal.is_reached_on(layout, board) {
+            return true;
+        }
+
+        // Every move removes a token, so there is no way back up to the goal's count.
+        if board.token_count() <= self.goal.token_count() {
+            return false;
+        }
+
+        if self.is_hopeless(board) {
+            return false;
+        }
+
+        let key = self.canonical(board);
+        if self.dead_ends.contains(&key) {
+            return false;
+        }
+
+        if self.is_stopped() {
+            return false;
+        }
+
+        self.nodes += 1;
+
+        for jump in layout.jumps(board) {
+            jumps.push(jump);
+            if self.search(layout, layout.apply(board, &jump), jumps) {
+                return true;
+            }
+
+            jumps.pop();
+        }
+
+        // Jumps skipped after a cancel or running out of positions do not make it a dead end.
+        if !self.is_stopped() {
+            self.dead_ends.insert(key);
+        }
+        false
+    }
+}
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Review {
+    // The goal can still be reached from the final position.
+    Reachable,
+    // The goal could not be reached even before the first move.
+    UnreachableFromStart,
+    // The move at `index` in the history made the goal unreachable.
+    Mistake {
+        index: usize,
+        played: ValidMove,
+        // Moves that would have kept the goal reachable instead.
+        alternatives: Vec<ValidMove>,
+    },
+}
+
+impl Solver {
+    /**
+     * Replays the grid's history to find the first move after which the goal could no longer be
+     * reached. Once unreachable the goal stays unreachable, so the positions are bisected rather
+     * than all solved. Fails for boards too large to search. The verdict of a solver that ran out
+     * of positions to search is no proof, see `is_out_of_budget`.
+     */
+    pub fn review(&mut self, grid: &Grid) -> Result<Review, GridError> {
+        let mut positions = vec![grid.clone()];
+        let mut replay = grid.clone();
+        while replay.undo().is_some() {
+            positions.push(replay.clone());
+        }
+        positions.reverse();
+
+        // Positions before `reachable` are known to reach the goal, those from `unreachable` on
+        // are known not to.
+        let (mut reachable, mut unreachable) = (0, positions.len());
+        while reachable < unreachable {
+            let middle = (reachable + unreachable) / 2;
+            if self.solve(&positions[middle])?.is_some() {
+                reachable = middle + 1;
+            } else {
+                unreachable = middle;
+            }
+        }
+
+        if unreachable == positions.len() {
+            return Ok(Review::Reachable);
+        }
+        if unreachable == 0 {
+            return Ok(Review::UnreachableFromStart);
+        }
+
+        let index = unreachable - 1;
+        let before = &positions[index];
+        let played = grid.history[index];
+        let alternatives = before
+            .all_valid_moves()
+            .list
+            .into_iter()
+            .filter(|valid_move| *valid_move != played)
+            .filter(|valid_move| {
+                let mut after = before.clone();
+                after.apply_move(valid_move).is_ok() && matches!(self.solve(&after), Ok(Some(_)))
+            })
+            .collect();
+
+        Ok(Review::Mistake {
+            index,
+            played,
+            alternatives,
+        })
+    }
+}
@@ -0,0 +1,385 @@
+use super::*;
+
+fn replay(grid: &Grid, moves: &[ValidMove]) -> Grid {
+    let mut grid = grid.clone();
+    for valid_move in moves.iter() {
+        grid.apply_move(valid_move).unwrap();
+    }
+
+    grid
+}
+
+#[test]
+fn test_solve_english_board() {
+    let grid = Grid::new();
+    let goal = Goal::SinglePegAt(grid.center());
+    let moves = Solver::new(goal.clone()).solve(&grid).unwrap().unwrap();
+
+    assert_eq!(moves.len(), 31);
+    assert!(goal.is_reached(&replay(&grid, &moves)));
+    assert_eq!(Solver::new(goal).hint(&grid), Ok(Some(moves[0])));
+}
+
+#[test]
+fn test_solve_reached_goal() {
+    let grid: Grid = "
+  ...
+  ...
+...o...
+.......
+.......
+  ...
+  ...
+".parse().unwrap();
+
+    assert_eq!(Solver::new(Goal::SinglePeg).solve(&grid), Ok(Some(vec![])));
+    assert_eq!(Solver::new(Goal::SinglePegAt(grid.center())).solve(&grid), Ok(None));
+}
+
+#[test]
+fn test_solve_too_large_board() {
+    let grid = Grid::cross(13).unwrap();
+    assert_eq!(Solver::new(Goal::SinglePeg).solve(&grid), Err(GridError::TooLarge {tiles: 169}));
+    assert_eq!(Solver::new(Goal::SinglePeg).review(&grid), Err(GridError::TooLarge {tiles: 169}));
+}
+
+#[test]
+fn test_solve_single_peg_at() {
+    let grid: Grid = "
+  ...
+  ...
+...o...
+.oo....
+.......
+  ...
+  ...
+".parse().unwrap();
+
+    let goal = Goal::SinglePegAt(Position {row: 1, col: 3});
+    let moves = Solver::new(goal.clone()).solve(&grid).unwrap().unwrap();
+    assert_eq!(format_moves(&moves, Notation::Algebraic), "b4-d4-d2");
+    assert!(goal.is_reached(&replay(&grid, &moves)));
+
+    let goal = Goal::SinglePegAt(Position {row: 4, col: 3});
+    let moves = Solver::new(goal.clone()).solve(&grid).unwrap().unwrap();
+    assert_eq!(format_moves(&moves, Notation::Algebraic), "b4-d4 d3-d5");
+
+    assert_eq!(Solver::new(Goal::SinglePegAt(Position {row: 3, col: 0})).solve(&grid), Ok(None));
+}
+
+#[test]
+fn test_solve_pattern() {
+    let grid = Grid::new();
+    let target: Grid = "
+  ooo
+  o.o
+ooooooo
+ooo.ooo
+ooo.ooo
+  ooo
+  ooo
+".parse().unwrap();
+
+    let mut solver = Solver::new(Goal::pattern_of(&target));
+    let moves = solver.solve(&grid).unwrap().unwrap();
+    assert_eq!(replay(&grid, &moves).tiles, target.tiles);
+
+    let small: Grid = "
+  ...
+  ...
+..o....
+.oo....
+.......
+  ...
+  ...
+".parse().unwrap();
+    assert_eq!(Solver::new(Goal::pattern_of(&target)).solve(&small), Ok(None));
+}
+
+#[test]
+fn test_prove_unsolvable() {
+    let grid: Grid = "
+  o.o
+  ...
+.......
+.......
+.......
+  ...
+  ...
+".parse().unwrap();
+
+    let mut solver = Solver::new(Goal::SinglePeg);
+    assert_eq!(solver.solve(&grid), Ok(None));
+    assert_eq!(solver.nodes, 1);
+
+    // The same bits are a single jump on a narrow board, so the dead end must not carry over.
+    let narrow: Grid = "..\no.\no.\n..".parse().unwrap();
+    assert_eq!(solver.solve(&narrow).unwrap().map(|moves| moves.len()), Some(1));
+}
+
+#[test]
+fn test_cancel() {
+    let grid = Grid::new();
+    let cancel = Arc::new(AtomicBool::new(true));
+    let mut solver = Solver::new(Goal::SinglePeg).with_cancel(cancel.clone());
+    assert!(solver.is_cancelled());
+    assert_eq!(solver.solve(&grid), Ok(None));
+    assert_eq!(solver.solve_minimum_moves(&grid), Ok(None));
+    assert_eq!(solver.nodes, 0);
+
+    // Nothing was remembered from the cancelled searches.
+    cancel.store(false, Ordering::Relaxed);
+    assert!(solver.solve(&grid).unwrap().is_some());
+}
+
+#[test]
+fn test_node_limit() {
+    let grid = Grid::from_variant(BoardVariant::German);
+    let mut solver = Solver::new(Goal::SinglePeg).with_node_limit(1000);
+    assert_eq!(solver.solve(&grid), Ok(None));
+    assert!(solver.is_out_of_budget());
+    assert_eq!(solver.nodes, 1000);
+    assert!(solver.dead_ends.len() < 1000);
+
+    let mut solver = Solver::new(Goal::SinglePegAt(Position {row: 1, col: 3})).with_node_limit(1000);
+    let chain: Grid = "
+  ...
+  ...
+...o...
+.oo....
+.......
+  ...
+  ...
+".parse().unwrap();
+    assert!(solver.solve(&chain).unwrap().is_some());
+    assert!(!solver.is_out_of_budget());
+}
+
+#[test]
+fn test_pagoda_pruning() {
+    let mut grid: Grid = "
+  ..o
+  ...
+...o.oo
+.......
+..o....
+  ...
+  ...
+".parse().unwrap();
+    grid.variant = Some(BoardVariant::English);
+
+    let goal = Goal::SinglePegAt(grid.center());
+    let pagoda = Pagoda::for_grid(&grid).unwrap();
+    assert_eq!(goal.pagoda_value(&pagoda), Some(2));
+    assert_eq!(pagoda.value(&grid), 0);
+    assert!(goal.is_ruled_out_by(&pagoda, &grid));
+    assert!(!goal.is_ruled_out_by_class(&grid));
+    assert!(!Goal::SinglePeg.is_ruled_out_by(&pagoda, &grid));
+
+    let mut solver = Solver::new(goal);
+    assert_eq!(solver.solve(&grid), Ok(None));
+    assert_eq!(solver.nodes, 0);
+}
+
+#[test]
+fn test_position_class_rejection() {
+    let grid = Grid::from_variant(BoardVariant::French);
+    assert!(Goal::SinglePegAt(grid.center()).is_ruled_out_by_class(&grid));
+    assert!(!Goal::SinglePegAt(Position { row: 5, col: 3 }).is_ruled_out_by_class(&grid));
+    assert!(!Goal::SinglePeg.is_ruled_out_by_class(&grid));
+
+    let mut solver = Solver::new(Goal::SinglePegAt(grid.center()));
+    assert_eq!(solver.solve(&grid), Ok(None));
+    assert_eq!(solver.nodes, 0);
+}
+
+#[test]
+fn test_review() {
+    let mut grid = Grid::new();
+    while let Some(valid_move) = grid.all_valid_moves().list.first().copied() {
+        grid.apply_move(&valid_move).unwrap();
+    }
+    assert!(grid.token_count() > 1);
+
+    let review = Solver::new(Goal::SinglePeg).review(&grid).unwrap();
+    let (index, played, alternatives) = match review {
+        Review::Mistake {
+            index,
+            played,
+            alternatives,
+        } => (index, played, alternatives),
+        _ => panic!("expected a mistake, got {:?}", review),
+    };
+    assert_eq!(index, 19);
+    assert_eq!(played.to_notation(Notation::Algebraic), "c5-c3");
+    assert_eq!(format_moves(&alternatives, Notation::Algebraic), "d5-b5 e6-e4");
+
+    let mut before = grid.clone();
+    while before.history.len() > index {
+        before.undo();
+    }
+    assert!(Solver::new(Goal::SinglePeg).solve(&before).unwrap().is_some());
+    before.apply_move(&played).unwrap();
+    assert!(Solver::new(Goal::SinglePeg).solve(&before).unwrap().is_none());
+
+    let mut grid = Grid::new();
+    grid.apply_move(&grid.all_valid_moves().list[0]).unwrap();
+    assert_eq!(Solver::new(Goal::SinglePeg).review(&grid), Ok(Review::Reachable));
+
+    let stranded: Grid = "
+  o.o
+  ...
+.......
+.......
+.......
+  ...
+  ...
+".parse().unwrap();
+    assert_eq!(
+        Solver::new(Goal::SinglePeg).review(&stranded),
+        Ok(Review::UnreachableFromStart)
+    );
+}
+
+#[test]
+fn test_count_moves() {
+    assert_eq!(count_moves(&[]), 0);
+    assert_eq!(count_moves(&parse_moves("d2-d4").unwrap()), 1);
+    assert_eq!(count_moves(&parse_moves("d2-d4 f3-d3-d5 d6-d4").unwrap()), 3);
+}
+
+#[test]
+fn test_solve_minimum_moves() {
+    let mut grid: Grid = "
+  ...
+  o..
+....o..
+..o....
+ooooooo
+  ooo
+  ooo
+".parse().unwrap();
+    grid.variant = Some(BoardVariant::English);
+
+    let goal = Goal::SinglePegAt(grid.center());
+    let any = Solver::new(goal.clone()).solve(&grid).unwrap().unwrap();
+    let best = Solver::new(goal.clone()).solve_minimum_moves(&grid).unwrap().unwrap();
+
+    assert_eq!(count_moves(&best), 12);
+    assert!(count_moves(&any) >= count_moves(&best));
+    assert_eq!(best.len(), any.len());
+    assert!(goal.is_reached(&replay(&grid, &best)));
+
+    let chain: Grid = "
+  ...
+  ...
+...o...
+.oo....
+.......
+  ...
+  ...
+".parse().unwrap();
+    let goal = Goal::SinglePegAt(Position {row: 1, col: 3});
+    let best = Solver::new(goal.clone()).solve_minimum_moves(&chain).unwrap().unwrap();
+    assert_eq!(format_moves(&best, Notation::Algebraic), "b4-d4-d2");
+    assert_eq!(count_moves(&best), 1);
+
+    assert_eq!(Solver::new(goal).solve_minimum_moves(&Grid::new()), Ok(None));
+    assert_eq!(Solver::new(Goal::SinglePeg).solve_minimum_moves(&replay(&chain, &best)), Ok(Some(vec![])));
+}
+
+#[test]
+fn test_complement_table() {
+    let table = ComplementTable::new(&Grid::from_mask(4, 3, &[true; 12])).unwrap();
+    let text = table.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 12);
+    assert_eq!(lines[0], "a1: a1* d1");
+    assert_eq!(lines[3], "d1: a1 d1*");
+    assert_eq!(lines[4], "a2:");
+    assert_eq!(table.complements().len(), 8);
+    assert_eq!(table.finishes_from(&Position {row: 1, col: 1}), Some(&vec![]));
+    assert_eq!(table.finishes_from(&Position {row: 3, col: 0}), None);
+
+    let square = ComplementTable::new(&Grid::from_mask(4, 4, &[true; 16])).unwrap();
+    assert_eq!(square.complements(), vec![]);
+    assert_eq!(
+        square.finishes_from(&Position {row: 0, col: 1}),
+        Some(&vec![Position {row: 0, col: 2}, Position {row: 3, col: 2}])
+    );
+
+    let finish = Position {row: 3, col: 2};
+    let start = Grid::from_mask(4, 4, &[true; 16]).with_vacancy(&Position {row: 0, col: 1}).unwrap();
+    assert!(Solver::new(Goal::SinglePegAt(finish)).solve(&start).unwrap().is_some());
+
+    let empty = ComplementTable::new(&Grid::from_mask(2, 2, &[false; 4])).unwrap();
+    assert_eq!(empty.finishes, vec![]);
+}
+
+#[test]
+fn test_classic_puzzles() {
+    let names: Vec<String> = Puzzle::classics().into_iter().map(|puzzle| puzzle.name).collect();
+    assert_eq!(names, vec!["Cross", "Plus", "Fireplace", "Pyramid", "Arrow"]);
+
+    for puzzle in Puzzle::classics() {
+        let grid = puzzle.grid();
+        let solution = Solver::new(Goal::for_grid(&grid)).solve(&grid).unwrap().unwrap();
+        assert_eq!(solution.len(), puzzle.token_count() - 1);
+
+        let solved = replay(&grid, &solution);
+        assert!(Goal::Pattern(puzzle.goal.clone()).is_reached(&solved));
+        assert_eq!(solved.game_result(), Some(GameResult::Perfect));
+    }
+}
+
+#[test]
+fn test_classic_puzzle_par() {
+    for puzzle in Puzzle::classics() {
+        assert_eq!(puzzle.par, puzzle.solution.as_deref().map(count_moves), "{}", puzzle.name);
+    }
+
+    let cross = Puzzle::from_name("cross").unwrap();
+    let grid = cross.grid();
+    assert_eq!(Solver::new(Goal::for_grid(&grid)).solve_minimum_moves(&grid).unwrap().map(|solution| count_moves(&solution)), cross.par);
+}
+
+#[test]
+fn test_solve_minimum_moves_english_board() {
+    // Found by `solve_minimum_moves` from the standard start, which takes a while.
+    let record = parse_moves("d2-d4 b3-d3 c1-c3 c4-c2 e3-c3 g3-e3 c6-c4 a5-c5 d5-b5-b3-d3-f3 e1-c1-c3-c5 f5-d5-b5 a3-a5-c5 g5-g3-e3 e7-e5 e4-e6 c7-e7-e5 e2-e4-c4-c6-e6-e4 f4-d4").unwrap();
+    let goal = Goal::for_grid(&Grid::new());
+    assert_eq!(count_moves(&record), 18);
+    assert!(goal.is_reached(&replay(&Grid::new(), &record)));
+
+    // Halfway through, the rest of the record cannot be beaten either.
+    let grid = replay(&Grid::new(), &record[..12]);
+    assert_eq!(count_moves(&record[..12]), 9);
+    let best = Solver::new(goal.clone()).solve_minimum_moves(&grid).unwrap().unwrap();
+    assert_eq!(count_moves(&best), 9);
+    assert!(goal.is_reached(&replay(&grid, &best)));
+}
+
+#[test]
+fn test_canonical_move_state() {
+    let grid = Grid::new();
+    let layout = BitboardLayout::new(&grid).unwrap();
+    let mut solver = Solver::new(Goal::for_grid(&grid));
+    solver.prepare(&grid).unwrap();
+
+    // The mirror image of a position with the same token about to jump again.
+    let jumps = ["d2-d4", "b4-d4", "f4-d4", "d6-d4"].map(|jump| parse_moves(jump).unwrap()[0]);
+    let states: Vec<(Bitboard, Option<usize>)> = jumps.iter().map(|jump| {
+        let board = layout.to_bitboard(&replay(&grid, &[*jump]));
+        solver.canonical_move_state(board, Some(grid.to_index(&jump.target)))
+    }).collect();
+    assert!(states.iter().all(|state| *state == states[0]));
+
+    // The jumping token is carried along with the board.
+    let board = layout.to_bitboard(&replay(&grid, &jumps[..1]));
+    let state = |position: Position| solver.canonical_move_state(board, Some(grid.to_index(&position)));
+    let (key, jumping) = state(Position {row: 4, col: 2});
+    assert_eq!(key.0 & (1 << jumping.unwrap()), 1 << jumping.unwrap());
+    assert_eq!(state(Position {row: 4, col: 4}), (key, jumping));
+    assert_ne!(state(Position {row: 5, col: 3}), (key, jumping));
+}